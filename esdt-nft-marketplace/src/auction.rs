@@ -3,6 +3,7 @@ multiversx_sc::derive_imports!();
 
 pub const PERCENTAGE_TOTAL: u64 = 10_000; // 100%
 pub const NFT_AMOUNT: u32 = 1; // Token has to be unique to be considered NFT
+pub const MAX_BUNDLE_TOTAL_WEIGHT: u64 = 1_000_000;
 
 #[derive(TopEncode, TopDecode, TypeAbi, Clone)]
 pub struct Auction<M: ManagedTypeApi> {
@@ -30,6 +31,27 @@ pub enum AuctionType {
     Nft,
     SftAll,
     SftOnePerPayment,
+    Dutch,
+    SealedBid,
+    Bundle,
+    FixedPrice,
+    UniformPrice,
+}
+
+impl AuctionType {
+    pub fn is_listing(&self) -> bool {
+        matches!(
+            self,
+            AuctionType::FixedPrice | AuctionType::SftOnePerPayment
+        )
+    }
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum ListingCloseReason {
+    Withdrawn,
+    SoldOut,
+    Expired,
 }
 
 #[multiversx_sc::module]
@@ -38,6 +60,10 @@ pub trait AuctionModule:
     + crate::token_whitelist::TokenWhitelistModule
     + crate::events::EventsModule
     + crate::common_util_functions::CommonUtilFunctions
+    + crate::soft_close::SoftCloseModule
+    + crate::sealed_bid::SealedBidModule
+    + crate::proxy_bid::ProxyBidModule
+    + crate::uniform_price::UniformPriceModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[payable("*")]
@@ -53,26 +79,38 @@ pub trait AuctionModule:
         opt_sft_max_one_per_payment: OptionalValue<bool>,
         opt_accepted_payment_token_nonce: OptionalValue<u64>,
         opt_start_time: OptionalValue<u64>,
+        opt_second_price: OptionalValue<bool>,
+        bundle_weights: MultiValueEncoded<u64>,
     ) -> u64 {
         self.require_not_paused();
 
-        let (nft_type, nft_nonce, nft_amount) = self.call_value().single_esdt().into_tuple();
+        let payments = self.call_value().all_esdt_transfers();
+        require!(!payments.is_empty(), "No tokens sent for auction");
+
+        let (nft_type, nft_nonce, nft_amount) = payments.get(0).into_tuple();
+        let is_bundle = payments.len() > 1;
+        require!(
+            is_bundle || bundle_weights.is_empty(),
+            "Bundle weights can only be set for bundles"
+        );
 
         let current_time = self.blockchain().get_block_timestamp();
-        let start_time = match opt_start_time {
-            OptionalValue::Some(0) => current_time,
-            OptionalValue::Some(st) => st,
-            OptionalValue::None => current_time,
-        };
+        let start_time = self.resolve_start_time(opt_start_time, current_time);
         let sft_max_one_per_payment = opt_sft_max_one_per_payment
             .into_option()
             .unwrap_or_default();
+        let second_price = opt_second_price.into_option().unwrap_or_default();
 
         if sft_max_one_per_payment {
             require!(
                 min_bid == max_bid,
                 "Price must be fixed for this type of auction (min bid equal to max bid)"
             );
+            require!(
+                !second_price,
+                "Second price settlement is not available for this type of auction"
+            );
+            require!(!is_bundle, "Bundles can't be sold one per payment");
         }
 
         let opt_max_bid = if max_bid > 0u32 {
@@ -83,43 +121,9 @@ pub trait AuctionModule:
             None
         };
 
-        require!(min_bid > 0, "Min bid must be higher than 0");
-        require!(
-            nft_nonce > 0,
-            "Only Semi-Fungible and Non-Fungible tokens can be auctioned"
-        );
-        require!(deadline > current_time, "Deadline can't be in the past");
-        require!(
-            start_time >= current_time && start_time < deadline,
-            "Invalid start time"
-        );
-
-        let marketplace_cut_percentage = self.bid_cut_percentage().get();
-        let creator_royalties_percentage = self.get_nft_info(&nft_type, nft_nonce).royalties;
-
-        require!(
-            &marketplace_cut_percentage + &creator_royalties_percentage < PERCENTAGE_TOTAL,
-            "Marketplace cut plus royalties exceeds 100%"
-        );
-
-        let min_bid_diff = match opt_min_bid_diff {
-            OptionalValue::Some(min_diff) => min_diff,
-            OptionalValue::None => BigUint::zero(),
-        };
-
-        self.require_token_whitelisted(&accepted_payment_token);
-        let accepted_payment_nft_nonce = if accepted_payment_token.is_egld() {
-            0
-        } else {
-            opt_accepted_payment_token_nonce
-                .into_option()
-                .unwrap_or_default()
-        };
-
-        let auction_id = self.last_valid_auction_id().get() + 1;
-        self.last_valid_auction_id().set(auction_id);
-
-        let auction_type = if nft_amount > NFT_AMOUNT {
+        let auction_type = if is_bundle {
+            AuctionType::Bundle
+        } else if nft_amount > NFT_AMOUNT {
             match sft_max_one_per_payment {
                 true => AuctionType::SftOnePerPayment,
                 false => AuctionType::SftAll,
@@ -128,108 +132,1204 @@ pub trait AuctionModule:
             AuctionType::Nft
         };
 
-        let auction = Auction {
-            auctioned_tokens: EsdtTokenPayment::new(nft_type, nft_nonce, nft_amount),
+        let mut auction = self.new_auction(
+            EsdtTokenPayment::new(nft_type, nft_nonce, nft_amount),
             auction_type,
-            payment_token: accepted_payment_token,
-            payment_nonce: accepted_payment_nft_nonce,
+            accepted_payment_token,
+            opt_accepted_payment_token_nonce,
             min_bid,
-            max_bid: opt_max_bid,
-            min_bid_diff,
+            opt_max_bid,
             start_time,
             deadline,
+        );
+        auction.min_bid_diff = self.resolve_min_bid_diff(opt_min_bid_diff);
 
-            original_owner: self.blockchain().get_caller(),
-            current_bid: BigUint::zero(),
-            current_winner: ManagedAddress::zero(),
-            marketplace_cut_percentage,
-            creator_royalties_percentage,
+        let (bundle_weights, bundle_royalties) = if is_bundle {
+            self.set_auction_bundle(&mut auction, &payments, bundle_weights.to_vec())
+        } else {
+            (ManagedVec::new(), ManagedVec::new())
         };
-        self.auction_by_id(auction_id).set(&auction);
 
-        self.emit_auction_token_event(auction_id, auction);
+        let auction_id = self.create_auction(auction.clone(), 0);
+        self.second_price(auction_id).set(second_price);
+        if is_bundle {
+            self.bundled_tokens(auction_id).set(&payments);
+            self.bundle_weights(auction_id).set(&bundle_weights);
+            self.bundle_royalties(auction_id).set(&bundle_royalties);
+            self.emit_auction_bundle_event(auction_id, &auction, &bundle_weights, &payments);
+        }
 
         auction_id
     }
 
-    #[endpoint(endAuction)]
-    fn end_auction(&self, auction_id: u64) {
+    #[payable("*")]
+    #[endpoint(listToken)]
+    fn list_token(
+        &self,
+        price: BigUint,
+        accepted_payment_token: EgldOrEsdtTokenIdentifier,
+        opt_deadline: OptionalValue<u64>,
+        opt_accepted_payment_token_nonce: OptionalValue<u64>,
+        opt_start_time: OptionalValue<u64>,
+        other_prices: MultiValueEncoded<MultiValue3<EgldOrEsdtTokenIdentifier, u64, BigUint>>,
+    ) -> u64 {
         self.require_not_paused();
 
-        let auction = self.try_get_auction(auction_id);
+        let auctioned_tokens = self.call_value().single_esdt();
+
         let current_time = self.blockchain().get_block_timestamp();
+        let start_time = self.resolve_start_time(opt_start_time, current_time);
+        let deadline = opt_deadline.into_option().unwrap_or_default();
 
-        let deadline_reached = current_time > auction.deadline;
-        let max_bid_reached = if let Some(max_bid) = &auction.max_bid {
-            &auction.current_bid == max_bid
+        let auction_type = if auctioned_tokens.amount > NFT_AMOUNT {
+            AuctionType::SftOnePerPayment
         } else {
-            false
+            AuctionType::FixedPrice
         };
 
-        require!(
-            deadline_reached || max_bid_reached,
-            "Auction deadline has not passed nor is the current bid equal to max bid"
+        let auction = self.new_auction(
+            auctioned_tokens,
+            auction_type,
+            accepted_payment_token,
+            opt_accepted_payment_token_nonce,
+            price.clone(),
+            Some(price),
+            start_time,
+            deadline,
         );
+
+        let auction_id = self.create_auction(auction.clone(), 0);
+        self.set_listing_prices(auction_id, &auction, other_prices);
+
+        auction_id
+    }
+
+    #[payable("*")]
+    #[endpoint(auctionTokenDutch)]
+    fn auction_token_dutch(
+        &self,
+        floor_price: BigUint,
+        starting_price: BigUint,
+        deadline: u64,
+        accepted_payment_token: EgldOrEsdtTokenIdentifier,
+        opt_accepted_payment_token_nonce: OptionalValue<u64>,
+        opt_start_time: OptionalValue<u64>,
+    ) -> u64 {
+        self.require_not_paused();
+
+        let auctioned_tokens = self.call_value().single_esdt();
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let start_time = self.resolve_start_time(opt_start_time, current_time);
+
         require!(
-            auction.auction_type != AuctionType::SftOnePerPayment,
-            "Cannot end this type of auction"
+            starting_price > floor_price,
+            "Starting price must be higher than the floor price"
+        );
+
+        let auction = self.new_auction(
+            auctioned_tokens,
+            AuctionType::Dutch,
+            accepted_payment_token,
+            opt_accepted_payment_token_nonce,
+            floor_price,
+            Some(starting_price),
+            start_time,
+            deadline,
         );
 
-        self.end_auction_common(auction_id, auction);
+        self.create_auction(auction, 0)
     }
 
-    fn end_auction_common(&self, auction_id: u64, auction: Auction<Self::Api>) {
-        self.distribute_tokens_after_auction_end(&auction, None);
-        self.auction_by_id(auction_id).clear();
+    #[payable("*")]
+    #[endpoint(auctionTokenReserve)]
+    #[allow(clippy::too_many_arguments)]
+    fn auction_token_reserve(
+        &self,
+        reserve_price: BigUint,
+        max_bid: BigUint,
+        duration: u64,
+        accepted_payment_token: EgldOrEsdtTokenIdentifier,
+        opt_min_bid_diff: OptionalValue<BigUint>,
+        opt_accepted_payment_token_nonce: OptionalValue<u64>,
+        opt_start_time: OptionalValue<u64>,
+        opt_second_price: OptionalValue<bool>,
+    ) -> u64 {
+        self.require_not_paused();
 
-        self.emit_end_auction_event(auction_id, auction);
+        let (nft_type, nft_nonce, nft_amount) = self.call_value().single_esdt().into_tuple();
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let start_time = self.resolve_start_time(opt_start_time, current_time);
+
+        require!(duration > 0, "Duration must be higher than 0");
+
+        let opt_max_bid = if max_bid > 0u32 {
+            require!(
+                reserve_price <= max_bid,
+                "Reserve price can't be higher than max bid"
+            );
+
+            Some(max_bid)
+        } else {
+            None
+        };
+
+        let auction_type = if nft_amount > NFT_AMOUNT {
+            AuctionType::SftAll
+        } else {
+            AuctionType::Nft
+        };
+
+        // the deadline of reserve auctions is only set once the first bid arrives
+        let mut auction = self.new_auction(
+            EsdtTokenPayment::new(nft_type, nft_nonce, nft_amount),
+            auction_type,
+            accepted_payment_token,
+            opt_accepted_payment_token_nonce,
+            reserve_price,
+            opt_max_bid,
+            start_time,
+            0,
+        );
+        auction.min_bid_diff = self.resolve_min_bid_diff(opt_min_bid_diff);
+
+        let auction_id = self.create_auction(auction, duration);
+        self.second_price(auction_id)
+            .set(opt_second_price.into_option().unwrap_or_default());
+
+        auction_id
     }
 
-    #[endpoint]
-    fn withdraw(&self, auction_id: u64) {
+    #[payable("*")]
+    #[endpoint(auctionTokenSealed)]
+    #[allow(clippy::too_many_arguments)]
+    fn auction_token_sealed(
+        &self,
+        min_bid: BigUint,
+        deadline: u64,
+        reveal_deadline: u64,
+        accepted_payment_token: EgldOrEsdtTokenIdentifier,
+        opt_accepted_payment_token_nonce: OptionalValue<u64>,
+        opt_start_time: OptionalValue<u64>,
+        opt_second_price: OptionalValue<bool>,
+    ) -> u64 {
         self.require_not_paused();
-        let caller = self.blockchain().get_caller();
-        let auction = self.try_get_auction(auction_id);
-        self.withdraw_auction_common(&caller, auction_id, auction.clone());
-        self.send_auction_nft(&caller, auction)
+
+        let auctioned_tokens = self.call_value().single_esdt();
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let start_time = self.resolve_start_time(opt_start_time, current_time);
+
+        require!(
+            reveal_deadline > deadline,
+            "Reveal deadline must be after the deadline"
+        );
+
+        let auction = self.new_auction(
+            auctioned_tokens,
+            AuctionType::SealedBid,
+            accepted_payment_token,
+            opt_accepted_payment_token_nonce,
+            min_bid,
+            None,
+            start_time,
+            deadline,
+        );
+
+        let auction_id = self.create_auction(auction, 0);
+        self.reveal_deadline(auction_id).set(reveal_deadline);
+        self.second_price(auction_id)
+            .set(opt_second_price.into_option().unwrap_or_default());
+
+        auction_id
     }
 
-    fn withdraw_auction_common(
+    // every bidder names a quantity and a unit price. At the deadline, the top bids fill the
+    // lot at the unit price of the lowest winning bid. A min quantity per bid keeps small bids
+    // from filling up the auction.
+    #[payable("*")]
+    #[endpoint(auctionTokenUniformPrice)]
+    fn auction_token_uniform_price(
         &self,
-        caller: &ManagedAddress,
-        auction_id: u64,
-        auction: Auction<Self::Api>,
-    ) {
+        min_unit_price: BigUint,
+        deadline: u64,
+        accepted_payment_token: EgldOrEsdtTokenIdentifier,
+        opt_accepted_payment_token_nonce: OptionalValue<u64>,
+        opt_start_time: OptionalValue<u64>,
+        opt_min_quantity: OptionalValue<BigUint>,
+    ) -> u64 {
+        self.require_not_paused();
+
+        let auctioned_tokens = self.call_value().single_esdt();
         require!(
-            &auction.original_owner == caller,
-            "Only the original owner can withdraw"
+            auctioned_tokens.amount > NFT_AMOUNT,
+            "Must auction more than 1 token"
         );
+        let min_quantity = opt_min_quantity.into_option().unwrap_or_default();
         require!(
-            auction.current_bid == 0 || auction.auction_type == AuctionType::SftOnePerPayment,
-            "Can't withdraw, NFT already has bids"
+            min_quantity <= auctioned_tokens.amount,
+            "Min quantity can't be higher than the number of auctioned tokens"
         );
-        self.auction_by_id(auction_id).clear();
-        self.emit_withdraw_event(auction_id, auction);
-    }
 
-    fn send_auction_nft(&self, caller: &ManagedAddress, auction: Auction<Self::Api>) {
-        self.transfer_or_save_payment(
-            caller,
-            &EgldOrEsdtTokenIdentifier::esdt(auction.auctioned_tokens.token_identifier),
-            auction.auctioned_tokens.token_nonce,
-            &auction.auctioned_tokens.amount,
+        let current_time = self.blockchain().get_block_timestamp();
+        let start_time = self.resolve_start_time(opt_start_time, current_time);
+
+        let auction = self.new_auction(
+            auctioned_tokens,
+            AuctionType::UniformPrice,
+            accepted_payment_token,
+            opt_accepted_payment_token_nonce,
+            min_unit_price,
+            None,
+            start_time,
+            deadline,
         );
+
+        let auction_id = self.create_auction(auction, 0);
+        self.uniform_price_min_quantity(auction_id)
+            .set(&min_quantity);
+
+        auction_id
     }
 
-    #[view(getFullAuctionData)]
-    fn try_get_auction(&self, auction_id: u64) -> Auction<Self::Api> {
-        let auction_mapper = self.auction_by_id(auction_id);
-        require!(!auction_mapper.is_empty(), "Auction does not exist");
-        auction_mapper.get()
+    fn resolve_start_time(&self, opt_start_time: OptionalValue<u64>, current_time: u64) -> u64 {
+        match opt_start_time {
+            OptionalValue::Some(0) => current_time,
+            OptionalValue::Some(st) => st,
+            OptionalValue::None => current_time,
+        }
     }
 
-    #[storage_mapper("auctionById")]
-    fn auction_by_id(&self, auction_id: u64) -> SingleValueMapper<Auction<Self::Api>>;
+    fn resolve_min_bid_diff(&self, opt_min_bid_diff: OptionalValue<BigUint>) -> BigUint {
+        match opt_min_bid_diff {
+            OptionalValue::Some(min_diff) => min_diff,
+            OptionalValue::None => BigUint::zero(),
+        }
+    }
+
+    fn resolve_payment_nonce(
+        &self,
+        accepted_payment_token: &EgldOrEsdtTokenIdentifier,
+        opt_accepted_payment_token_nonce: OptionalValue<u64>,
+    ) -> u64 {
+        if accepted_payment_token.is_egld() {
+            0
+        } else {
+            opt_accepted_payment_token_nonce
+                .into_option()
+                .unwrap_or_default()
+        }
+    }
+
+    fn set_listing_prices(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        other_prices: MultiValueEncoded<MultiValue3<EgldOrEsdtTokenIdentifier, u64, BigUint>>,
+    ) {
+        let mut listing_prices = self.listing_prices(auction_id);
+        for other_price in other_prices {
+            let (payment_token, payment_nonce, price) = other_price.into_tuple();
+            let payment_nonce =
+                self.resolve_payment_nonce(&payment_token, OptionalValue::Some(payment_nonce));
+
+            require!(price > 0, "Price must be higher than 0");
+            require!(
+                self.find_listing_price(auction_id, auction, &payment_token, payment_nonce)
+                    .is_none(),
+                "Price already set for this payment token"
+            );
+            self.require_token_whitelisted(&payment_token);
+
+            listing_prices.insert((payment_token.clone(), payment_nonce), price.clone());
+            self.emit_listing_price_event(auction_id, auction, payment_token, payment_nonce, price);
+        }
+    }
+
+    fn find_listing_price(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        payment_token: &EgldOrEsdtTokenIdentifier,
+        payment_nonce: u64,
+    ) -> Option<BigUint> {
+        if payment_token == &auction.payment_token && payment_nonce == auction.payment_nonce {
+            return Some(auction.min_bid.clone());
+        }
+
+        self.listing_prices(auction_id)
+            .get(&(payment_token.clone(), payment_nonce))
+    }
+
+    // returns the weight and the royalties of each bundled token
+    fn set_auction_bundle(
+        &self,
+        auction: &mut Auction<Self::Api>,
+        bundled_tokens: &ManagedVec<EsdtTokenPayment>,
+        mut bundle_weights: ManagedVec<u64>,
+    ) -> (ManagedVec<u64>, ManagedVec<BigUint>) {
+        // items are weighted equally if the seller did not declare any weights
+        if bundle_weights.is_empty() {
+            for _ in 0..bundled_tokens.len() {
+                bundle_weights.push(1);
+            }
+        }
+        require!(
+            bundle_weights.len() == bundled_tokens.len(),
+            "Number of weights does not match the number of bundled tokens"
+        );
+
+        let mut total_weight = 0u64;
+        let mut weighted_royalties = BigUint::zero();
+        let mut bundle_royalties = ManagedVec::new();
+        for (item, weight) in bundled_tokens.iter().zip(bundle_weights.iter()) {
+            require!(
+                item.token_nonce > 0,
+                "Only Semi-Fungible and Non-Fungible tokens can be auctioned"
+            );
+            require!(weight > 0, "Bundle weights must be higher than 0");
+            require!(
+                weight <= MAX_BUNDLE_TOTAL_WEIGHT - total_weight,
+                "Total bundle weight exceeds the maximum"
+            );
+
+            let item_royalties = self
+                .get_nft_info(&item.token_identifier, item.token_nonce)
+                .royalties;
+            require!(
+                &auction.marketplace_cut_percentage + &item_royalties < PERCENTAGE_TOTAL,
+                "Marketplace cut plus royalties exceeds 100%"
+            );
+
+            weighted_royalties += &item_royalties * weight;
+            total_weight += weight;
+            bundle_royalties.push(item_royalties);
+        }
+
+        auction.creator_royalties_percentage = weighted_royalties / total_weight;
+
+        (bundle_weights, bundle_royalties)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn new_auction(
+        &self,
+        auctioned_tokens: EsdtTokenPayment,
+        auction_type: AuctionType,
+        accepted_payment_token: EgldOrEsdtTokenIdentifier,
+        opt_accepted_payment_token_nonce: OptionalValue<u64>,
+        min_bid: BigUint,
+        max_bid: Option<BigUint>,
+        start_time: u64,
+        deadline: u64,
+    ) -> Auction<Self::Api> {
+        let accepted_payment_nft_nonce =
+            self.resolve_payment_nonce(&accepted_payment_token, opt_accepted_payment_token_nonce);
+        let creator_royalties_percentage = self
+            .get_nft_info(
+                &auctioned_tokens.token_identifier,
+                auctioned_tokens.token_nonce,
+            )
+            .royalties;
+
+        Auction {
+            auctioned_tokens,
+            auction_type,
+            payment_token: accepted_payment_token,
+            payment_nonce: accepted_payment_nft_nonce,
+            min_bid,
+            max_bid,
+            min_bid_diff: BigUint::zero(),
+            start_time,
+            deadline,
+
+            original_owner: self.blockchain().get_caller(),
+            current_bid: BigUint::zero(),
+            current_winner: ManagedAddress::zero(),
+            marketplace_cut_percentage: self.bid_cut_percentage().get(),
+            creator_royalties_percentage,
+        }
+    }
+
+    // reserve auctions have a countdown instead of a deadline, which starts on the first bid
+    fn create_auction(&self, auction: Auction<Self::Api>, countdown_duration: u64) -> u64 {
+        let current_time = self.blockchain().get_block_timestamp();
+
+        require!(auction.min_bid > 0, "Min bid must be higher than 0");
+        require!(
+            auction.auctioned_tokens.token_nonce > 0,
+            "Only Semi-Fungible and Non-Fungible tokens can be auctioned"
+        );
+        if countdown_duration == 0 && !self.is_open_ended(&auction) {
+            require!(
+                auction.deadline > current_time,
+                "Deadline can't be in the past"
+            );
+            require!(
+                auction.start_time >= current_time && auction.start_time < auction.deadline,
+                "Invalid start time"
+            );
+        } else {
+            require!(auction.start_time >= current_time, "Invalid start time");
+        }
+        require!(
+            &auction.marketplace_cut_percentage + &auction.creator_royalties_percentage
+                < PERCENTAGE_TOTAL,
+            "Marketplace cut plus royalties exceeds 100%"
+        );
+        self.require_token_whitelisted(&auction.payment_token);
+
+        let auction_id = self.last_valid_auction_id().get() + 1;
+        self.last_valid_auction_id().set(auction_id);
+        self.auction_by_id(auction_id).set(&auction);
+        self.countdown_duration(auction_id).set(countdown_duration);
+        self.soft_close_window(auction_id)
+            .set(self.default_soft_close_window().get());
+        self.soft_close_extension(auction_id)
+            .set(self.default_soft_close_extension().get());
+
+        self.emit_auction_token_event(auction_id, auction);
+
+        auction_id
+    }
+
+    // listings created without a deadline stay active until sold or withdrawn
+    fn is_open_ended(&self, auction: &Auction<Self::Api>) -> bool {
+        auction.deadline == 0 && auction.auction_type.is_listing()
+    }
+
+    #[endpoint(setAuctionSoftClose)]
+    fn set_auction_soft_close(&self, auction_id: u64, window: u64, extension: u64) {
+        self.require_not_paused();
+
+        let auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            auction.original_owner == caller,
+            "Only the original owner can change the soft close"
+        );
+        require!(
+            matches!(
+                auction.auction_type,
+                AuctionType::Nft | AuctionType::SftAll | AuctionType::Bundle
+            ),
+            "Cannot set soft close for this type of auction"
+        );
+        require!(auction.current_bid == 0, "Auction already has bids");
+        self.require_valid_soft_close(window, extension);
+
+        self.soft_close_window(auction_id).set(window);
+        self.soft_close_extension(auction_id).set(extension);
+    }
+
+    #[endpoint(setAuctionMinBidDiffPercentage)]
+    fn set_auction_min_bid_diff_percentage(&self, auction_id: u64, min_bid_diff_percentage: u64) {
+        self.require_not_paused();
+
+        let auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            auction.original_owner == caller,
+            "Only the original owner can change the min bid diff"
+        );
+        require!(auction.current_bid == 0, "Auction already has bids");
+        self.require_valid_min_bid_diff_percentage(&auction, min_bid_diff_percentage);
+
+        let old_min_bid_diff_percentage = self
+            .min_bid_diff_percentage(auction_id)
+            .replace(min_bid_diff_percentage);
+
+        self.emit_update_auction_event(
+            auction_id,
+            auction.clone(),
+            auction,
+            old_min_bid_diff_percentage,
+            min_bid_diff_percentage,
+        );
+    }
+
+    fn require_valid_min_bid_diff_percentage(
+        &self,
+        auction: &Auction<Self::Api>,
+        min_bid_diff_percentage: u64,
+    ) {
+        require!(
+            matches!(
+                auction.auction_type,
+                AuctionType::Nft | AuctionType::SftAll | AuctionType::Bundle
+            ),
+            "Cannot set min bid diff for this type of auction"
+        );
+        require!(
+            min_bid_diff_percentage <= PERCENTAGE_TOTAL,
+            "Invalid min bid diff percentage"
+        );
+    }
+
+    // offers for the auctioned token must be at least this amount per unit, in the auction's token
+    #[endpoint(setListingMinOffer)]
+    fn set_listing_min_offer(&self, auction_id: u64, min_offer: BigUint) {
+        self.require_not_paused();
+
+        let auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            auction.original_owner == caller,
+            "Only the original owner can set the min offer"
+        );
+
+        if min_offer == 0 {
+            self.listing_min_offer(auction_id).clear();
+        } else {
+            self.listing_min_offer(auction_id).set(&min_offer);
+        }
+    }
+
+    // changes the price in one of the other tokens accepted by a listing. A price of 0 stops
+    // accepting the token, while the main payment token is changed through updateAuction.
+    #[endpoint(updateListingPrice)]
+    fn update_listing_price(
+        &self,
+        auction_id: u64,
+        payment_token: EgldOrEsdtTokenIdentifier,
+        payment_nonce: u64,
+        price: BigUint,
+    ) {
+        self.require_not_paused();
+
+        let auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+        let payment_nonce =
+            self.resolve_payment_nonce(&payment_token, OptionalValue::Some(payment_nonce));
+
+        require!(
+            auction.original_owner == caller,
+            "Only the original owner can update the listing prices"
+        );
+        require!(auction.auction_type.is_listing(), "Not a listing");
+        require!(
+            !(payment_token == auction.payment_token && payment_nonce == auction.payment_nonce),
+            "The main price can only be changed through updateAuction"
+        );
+
+        let mut listing_prices = self.listing_prices(auction_id);
+        let price_key = (payment_token.clone(), payment_nonce);
+        if price == 0 {
+            require!(
+                listing_prices.remove(&price_key).is_some(),
+                "No price set for this payment token"
+            );
+        } else {
+            // buyers may already have seen the listing, so prices can only go down
+            if auction.auction_type == AuctionType::SftOnePerPayment {
+                if let Some(old_price) = listing_prices.get(&price_key) {
+                    require!(
+                        price <= old_price,
+                        "Price can only be lowered for this type of auction"
+                    );
+                }
+            }
+            self.require_token_whitelisted(&payment_token);
+            listing_prices.insert(price_key, price.clone());
+        }
+
+        self.emit_listing_price_event(auction_id, &auction, payment_token, payment_nonce, price);
+    }
+
+    // buyers can take the buy-now price until the first bid is placed, after which it is removed
+    #[endpoint(setAuctionBuyNowPrice)]
+    fn set_auction_buy_now_price(&self, auction_id: u64, buy_now_price: BigUint) {
+        self.require_not_paused();
+
+        let auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            auction.original_owner == caller,
+            "Only the original owner can set the buy-now price"
+        );
+        require!(
+            matches!(
+                auction.auction_type,
+                AuctionType::Nft | AuctionType::SftAll | AuctionType::Bundle
+            ),
+            "Cannot set a buy-now price for this type of auction"
+        );
+        require!(auction.current_bid == 0, "Auction already has bids");
+
+        if buy_now_price == 0 {
+            self.buy_now_price(auction_id).clear();
+        } else {
+            self.require_valid_buy_now_price(&auction, &buy_now_price);
+            self.buy_now_price(auction_id).set(&buy_now_price);
+        }
+    }
+
+    fn require_valid_buy_now_price(&self, auction: &Auction<Self::Api>, buy_now_price: &BigUint) {
+        require!(
+            buy_now_price >= &auction.min_bid,
+            "Buy-now price can't be lower than the min bid"
+        );
+        if let Some(max_bid) = &auction.max_bid {
+            require!(
+                buy_now_price <= max_bid,
+                "Buy-now price can't be higher than the max bid"
+            );
+        }
+    }
+
+    // only the given addresses may bid on or buy the auctioned token. An empty list removes the
+    // restriction, unless a required token is set.
+    #[endpoint(setAuctionAllowlist)]
+    fn set_auction_allowlist(&self, auction_id: u64, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_not_paused();
+
+        let auction = self.try_get_auction(auction_id);
+        self.require_can_set_allowed_buyers(auction_id, &auction);
+
+        let mut allowlist_mapper = self.auction_allowlist(auction_id);
+        allowlist_mapper.clear();
+        for address in addresses {
+            let _ = allowlist_mapper.insert(address);
+        }
+    }
+
+    // holders of the given token may bid on or buy the auctioned token, along with the
+    // allowlisted addresses
+    #[endpoint(setAuctionRequiredToken)]
+    fn set_auction_required_token(
+        &self,
+        auction_id: u64,
+        opt_required_token: OptionalValue<MultiValue2<TokenIdentifier, u64>>,
+    ) {
+        self.require_not_paused();
+
+        let auction = self.try_get_auction(auction_id);
+        self.require_can_set_allowed_buyers(auction_id, &auction);
+
+        match opt_required_token {
+            OptionalValue::Some(required_token) => {
+                let (token_id, token_nonce) = required_token.into_tuple();
+                require!(token_id.is_valid_esdt_identifier(), "Invalid token ID");
+
+                self.auction_required_token(auction_id)
+                    .set(&(token_id, token_nonce));
+            }
+            OptionalValue::None => self.auction_required_token(auction_id).clear(),
+        }
+    }
+
+    fn require_can_set_allowed_buyers(&self, auction_id: u64, auction: &Auction<Self::Api>) {
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            auction.original_owner == caller,
+            "Only the original owner can set the allowed buyers"
+        );
+        require!(
+            auction.current_bid == 0
+                && self.sealed_bids(auction_id).is_empty()
+                && self.uniform_price_bids(auction_id).is_empty(),
+            "Allowed buyers can only be changed before the first sale"
+        );
+    }
+
+    fn is_allowed_buyer(
+        &self,
+        auction_id: u64,
+        buyer: &ManagedAddress,
+        opt_presented_token: Option<&EsdtTokenPayment<Self::Api>>,
+    ) -> bool {
+        let allowlist_mapper = self.auction_allowlist(auction_id);
+        let required_token_mapper = self.auction_required_token(auction_id);
+        if allowlist_mapper.is_empty() && required_token_mapper.is_empty() {
+            return true;
+        }
+        if allowlist_mapper.contains(buyer) {
+            return true;
+        }
+        if required_token_mapper.is_empty() {
+            return false;
+        }
+
+        let (token_id, token_nonce) = required_token_mapper.get();
+        match opt_presented_token {
+            Some(presented_token) => {
+                presented_token.token_identifier == token_id
+                    && presented_token.token_nonce == token_nonce
+                    && presented_token.amount > 0u64
+            }
+            None => false,
+        }
+    }
+
+    // a percentage of the bid, when set, replaces the absolute min bid diff
+    fn get_min_bid_diff(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        bid_amount: &BigUint,
+    ) -> BigUint {
+        let min_bid_diff_percentage = self.min_bid_diff_percentage(auction_id).get();
+        if min_bid_diff_percentage == 0 {
+            return auction.min_bid_diff.clone();
+        }
+
+        bid_amount * min_bid_diff_percentage / PERCENTAGE_TOTAL
+    }
+
+    #[endpoint(updateAuction)]
+    #[allow(clippy::too_many_arguments)]
+    fn update_auction(
+        &self,
+        auction_id: u64,
+        min_bid: BigUint,
+        max_bid: BigUint,
+        deadline: u64,
+        accepted_payment_token: EgldOrEsdtTokenIdentifier,
+        opt_min_bid_diff: OptionalValue<BigUint>,
+        opt_accepted_payment_token_nonce: OptionalValue<u64>,
+        opt_min_bid_diff_percentage: OptionalValue<u64>,
+    ) {
+        self.require_not_paused();
+
+        let mut auction = self.try_get_auction(auction_id);
+        let old_auction = auction.clone();
+        let caller = self.blockchain().get_caller();
+        let current_time = self.blockchain().get_block_timestamp();
+        let accepted_payment_nft_nonce =
+            self.resolve_payment_nonce(&accepted_payment_token, opt_accepted_payment_token_nonce);
+
+        require!(
+            auction.original_owner == caller,
+            "Only the original owner can update the auction"
+        );
+        require!(min_bid > 0, "Min bid must be higher than 0");
+
+        if auction.auction_type == AuctionType::SftOnePerPayment {
+            // buyers may already have seen the listing, so the price can only go down
+            require!(
+                min_bid == max_bid,
+                "Price must be fixed for this type of auction (min bid equal to max bid)"
+            );
+            require!(
+                min_bid <= auction.min_bid,
+                "Price can only be lowered for this type of auction"
+            );
+            require!(
+                deadline == auction.deadline
+                    && accepted_payment_token == auction.payment_token
+                    && accepted_payment_nft_nonce == auction.payment_nonce,
+                "Only the price can be changed for this type of auction"
+            );
+        } else {
+            require!(
+                matches!(
+                    auction.auction_type,
+                    AuctionType::Nft
+                        | AuctionType::SftAll
+                        | AuctionType::Bundle
+                        | AuctionType::FixedPrice
+                ),
+                "Cannot update this type of auction"
+            );
+            require!(auction.current_bid == 0, "Auction already has bids");
+            if auction.auction_type == AuctionType::FixedPrice {
+                require!(
+                    min_bid == max_bid,
+                    "Price must be fixed for this type of auction (min bid equal to max bid)"
+                );
+            }
+
+            if self.countdown_duration(auction_id).get() == 0
+                && !(deadline == 0 && auction.auction_type.is_listing())
+            {
+                require!(deadline > current_time, "Deadline can't be in the past");
+                require!(auction.start_time < deadline, "Invalid start time");
+            } else {
+                require!(deadline == 0, "Reserve auctions can't have a deadline");
+            }
+        }
+
+        auction.max_bid = if max_bid > 0u32 {
+            require!(min_bid <= max_bid, "Min bid can't be higher than max bid");
+
+            Some(max_bid)
+        } else {
+            None
+        };
+        if let OptionalValue::Some(min_bid_diff) = opt_min_bid_diff {
+            auction.min_bid_diff = min_bid_diff;
+        }
+        let min_bid_diff_percentage_mapper = self.min_bid_diff_percentage(auction_id);
+        let old_min_bid_diff_percentage = min_bid_diff_percentage_mapper.get();
+        if let OptionalValue::Some(min_bid_diff_percentage) = opt_min_bid_diff_percentage {
+            self.require_valid_min_bid_diff_percentage(&auction, min_bid_diff_percentage);
+            min_bid_diff_percentage_mapper.set(min_bid_diff_percentage);
+        }
+        self.require_token_whitelisted(&accepted_payment_token);
+        require!(
+            !self
+                .listing_prices(auction_id)
+                .contains_key(&(accepted_payment_token.clone(), accepted_payment_nft_nonce)),
+            "Price already set for this payment token"
+        );
+
+        auction.min_bid = min_bid;
+        auction.deadline = deadline;
+        auction.payment_token = accepted_payment_token;
+        auction.payment_nonce = accepted_payment_nft_nonce;
+
+        let buy_now_price_mapper = self.buy_now_price(auction_id);
+        if !buy_now_price_mapper.is_empty() {
+            self.require_valid_buy_now_price(&auction, &buy_now_price_mapper.get());
+        }
+        self.auction_by_id(auction_id).set(&auction);
+
+        self.emit_update_auction_event(
+            auction_id,
+            old_auction,
+            auction,
+            old_min_bid_diff_percentage,
+            min_bid_diff_percentage_mapper.get(),
+        );
+    }
+
+    #[endpoint(endAuction)]
+    fn end_auction(&self, auction_id: u64) {
+        self.require_not_paused();
+
+        let auction = self.try_get_auction(auction_id);
+
+        require!(
+            self.is_auction_over(auction_id, &auction),
+            "Auction deadline has not passed nor is the current bid equal to max bid"
+        );
+
+        self.settle_auction(auction_id, auction, None);
+    }
+
+    // ends every auction of the batch that can be ended, and skips the others. The caller is
+    // rewarded with part of the marketplace cut of each sale.
+    #[endpoint(endAuctions)]
+    fn end_auctions(&self, auction_ids: MultiValueEncoded<u64>) {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        for auction_id in auction_ids {
+            let auction_mapper = self.auction_by_id(auction_id);
+            if auction_mapper.is_empty() {
+                continue;
+            }
+
+            let auction = auction_mapper.get();
+            if !self.is_auction_over(auction_id, &auction) {
+                continue;
+            }
+
+            // the parties of the sale don't earn a reward for settling it
+            let opt_keeper = if caller == auction.current_winner || caller == auction.original_owner
+            {
+                None
+            } else {
+                Some(&caller)
+            };
+            self.settle_auction(auction_id, auction, opt_keeper);
+        }
+    }
+
+    fn is_auction_over(&self, auction_id: u64, auction: &Auction<Self::Api>) -> bool {
+        let current_time = self.blockchain().get_block_timestamp();
+
+        // sealed bid auctions can only be ended after the reveal phase
+        let end_time = if auction.auction_type == AuctionType::SealedBid {
+            self.reveal_deadline(auction_id).get()
+        } else {
+            auction.deadline
+        };
+        let deadline_reached = end_time > 0 && current_time > end_time;
+        // listings sold one per payment are only over at their deadline
+        let max_bid_reached = match &auction.max_bid {
+            Some(max_bid) if auction.auction_type != AuctionType::SftOnePerPayment => {
+                &auction.current_bid == max_bid
+            }
+            _ => false,
+        };
+
+        deadline_reached || max_bid_reached
+    }
+
+    fn settle_auction(
+        &self,
+        auction_id: u64,
+        auction: Auction<Self::Api>,
+        opt_keeper: Option<&ManagedAddress>,
+    ) {
+        if auction.auction_type == AuctionType::SftOnePerPayment {
+            self.expire_listing(auction_id, auction);
+            return;
+        }
+        if auction.auction_type == AuctionType::UniformPrice {
+            self.end_uniform_price_auction(auction_id, auction, opt_keeper);
+            return;
+        }
+        if auction.auction_type == AuctionType::SealedBid {
+            self.settle_unrevealed_sealed_bids(auction_id, &auction);
+        }
+
+        self.end_auction_common(auction_id, auction, opt_keeper);
+    }
+
+    fn end_uniform_price_auction(
+        &self,
+        auction_id: u64,
+        auction: Auction<Self::Api>,
+        opt_keeper: Option<&ManagedAddress>,
+    ) {
+        let settlement = self.settle_uniform_price_bids(auction_id, &auction, opt_keeper);
+        self.clear_auction(auction_id);
+
+        self.emit_end_uniform_price_auction_event(auction_id, auction, settlement);
+    }
+
+    // the tokens that were not sold are returned to the owner
+    fn expire_listing(&self, auction_id: u64, auction: Auction<Self::Api>) {
+        self.transfer_auctioned_tokens(&auction.original_owner, auction_id, &auction);
+        self.clear_auction(auction_id);
+
+        self.emit_close_listing_event(auction_id, auction, ListingCloseReason::Expired);
+    }
+
+    fn end_auction_common(
+        &self,
+        auction_id: u64,
+        mut auction: Auction<Self::Api>,
+        opt_keeper: Option<&ManagedAddress>,
+    ) {
+        self.refund_unused_proxy_bid(auction_id, &auction);
+
+        if self.second_price(auction_id).get() && !auction.current_winner.is_zero() {
+            self.apply_second_price(auction_id, &mut auction);
+        }
+
+        self.distribute_tokens_after_auction_end(auction_id, &auction, None, opt_keeper);
+        self.clear_auction(auction_id);
+
+        self.emit_end_auction_event(auction_id, auction);
+    }
+
+    fn apply_second_price(&self, auction_id: u64, auction: &mut Auction<Self::Api>) {
+        let second_highest_bid = self.second_highest_bid(auction_id).get();
+        let mut settlement_price =
+            &second_highest_bid + &self.get_min_bid_diff(auction_id, auction, &second_highest_bid);
+        if settlement_price < auction.min_bid {
+            settlement_price = auction.min_bid.clone();
+        }
+        if settlement_price >= auction.current_bid {
+            return;
+        }
+
+        // refund the winner the difference to the settlement price
+        self.transfer_or_save_payment(
+            &auction.current_winner,
+            &auction.payment_token,
+            auction.payment_nonce,
+            &(&auction.current_bid - &settlement_price),
+        );
+        auction.current_bid = settlement_price;
+    }
+
+    #[endpoint]
+    fn withdraw(&self, auction_id: u64) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        let auction = self.try_get_auction(auction_id);
+        self.withdraw_auction_common(&caller, auction_id, auction.clone());
+        self.send_auction_nft(&caller, auction_id, auction);
+        self.clear_auction(auction_id);
+    }
+
+    fn withdraw_auction_common(
+        &self,
+        caller: &ManagedAddress,
+        auction_id: u64,
+        auction: Auction<Self::Api>,
+    ) {
+        require!(
+            &auction.original_owner == caller,
+            "Only the original owner can withdraw"
+        );
+        require!(
+            auction.current_bid == 0 || auction.auction_type == AuctionType::SftOnePerPayment,
+            "Can't withdraw, NFT already has bids"
+        );
+        require!(
+            self.sealed_bids(auction_id).is_empty(),
+            "Can't withdraw, NFT already has bids"
+        );
+        require!(
+            self.uniform_price_bids(auction_id).is_empty(),
+            "Can't withdraw, NFT already has bids"
+        );
+        if auction.auction_type == AuctionType::SftOnePerPayment {
+            self.emit_close_listing_event(
+                auction_id,
+                auction.clone(),
+                ListingCloseReason::Withdrawn,
+            );
+        }
+        self.emit_withdraw_event(auction_id, auction);
+    }
+
+    fn send_auction_nft(
+        &self,
+        caller: &ManagedAddress,
+        auction_id: u64,
+        auction: Auction<Self::Api>,
+    ) {
+        self.transfer_auctioned_tokens(caller, auction_id, &auction);
+    }
+
+    #[view(getCurrentDutchPrice)]
+    fn get_current_dutch_price(&self, auction_id: u64) -> BigUint {
+        let auction = self.try_get_auction(auction_id);
+        require!(
+            auction.auction_type == AuctionType::Dutch,
+            "Not a dutch auction"
+        );
+
+        self.calculate_dutch_price(&auction)
+    }
+
+    fn calculate_dutch_price(&self, auction: &Auction<Self::Api>) -> BigUint {
+        let starting_price = match &auction.max_bid {
+            Some(price) => price,
+            None => return auction.min_bid.clone(),
+        };
+
+        let current_time = self.blockchain().get_block_timestamp();
+        if current_time <= auction.start_time {
+            return starting_price.clone();
+        }
+        if current_time >= auction.deadline {
+            return auction.min_bid.clone();
+        }
+
+        let elapsed_time = current_time - auction.start_time;
+        let total_duration = auction.deadline - auction.start_time;
+        let price_drop = (starting_price - &auction.min_bid) * elapsed_time / total_duration;
+
+        starting_price - &price_drop
+    }
+
+    #[view(isListing)]
+    fn is_listing(&self, auction_id: u64) -> bool {
+        self.try_get_auction(auction_id).auction_type.is_listing()
+    }
+
+    #[view(getListingPrice)]
+    fn get_listing_price(&self, auction_id: u64) -> BigUint {
+        let auction = self.try_get_auction(auction_id);
+        require!(auction.auction_type.is_listing(), "Not a listing");
+
+        auction.min_bid
+    }
+
+    #[view(getListingPrices)]
+    fn get_listing_prices(
+        &self,
+        auction_id: u64,
+    ) -> MultiValueEncoded<MultiValue3<EgldOrEsdtTokenIdentifier, u64, BigUint>> {
+        let auction = self.try_get_auction(auction_id);
+        require!(auction.auction_type.is_listing(), "Not a listing");
+
+        let mut prices = MultiValueEncoded::new();
+        prices.push(
+            (
+                auction.payment_token,
+                auction.payment_nonce,
+                auction.min_bid,
+            )
+                .into(),
+        );
+        for ((payment_token, payment_nonce), price) in self.listing_prices(auction_id).iter() {
+            prices.push((payment_token, payment_nonce, price).into());
+        }
+
+        prices
+    }
+
+    fn clear_auction(&self, auction_id: u64) {
+        self.auction_by_id(auction_id).clear();
+        self.listing_prices(auction_id).clear();
+        self.listing_min_offer(auction_id).clear();
+        self.buy_now_price(auction_id).clear();
+        self.auction_allowlist(auction_id).clear();
+        self.auction_required_token(auction_id).clear();
+        self.soft_close_window(auction_id).clear();
+        self.soft_close_extension(auction_id).clear();
+        self.countdown_duration(auction_id).clear();
+        self.reveal_deadline(auction_id).clear();
+        self.second_price(auction_id).clear();
+        self.second_highest_bid(auction_id).clear();
+        self.bundled_tokens(auction_id).clear();
+        self.bundle_weights(auction_id).clear();
+        self.bundle_royalties(auction_id).clear();
+        self.uniform_price_min_quantity(auction_id).clear();
+        self.min_bid_diff_percentage(auction_id).clear();
+    }
+
+    #[view(getFullAuctionData)]
+    fn try_get_auction(&self, auction_id: u64) -> Auction<Self::Api> {
+        let auction_mapper = self.auction_by_id(auction_id);
+        require!(!auction_mapper.is_empty(), "Auction does not exist");
+        auction_mapper.get()
+    }
+
+    #[storage_mapper("auctionById")]
+    fn auction_by_id(&self, auction_id: u64) -> SingleValueMapper<Auction<Self::Api>>;
+
+    #[view(getListingMinOffer)]
+    #[storage_mapper("listingMinOffer")]
+    fn listing_min_offer(&self, auction_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[view(getAuctionAllowlist)]
+    #[storage_mapper("auctionAllowlist")]
+    fn auction_allowlist(&self, auction_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getAuctionRequiredToken)]
+    #[storage_mapper("auctionRequiredToken")]
+    fn auction_required_token(&self, auction_id: u64) -> SingleValueMapper<(TokenIdentifier, u64)>;
+
+    #[view(getAuctionCountdownDuration)]
+    #[storage_mapper("countdownDuration")]
+    fn countdown_duration(&self, auction_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getAuctionSecondPrice)]
+    #[storage_mapper("secondPrice")]
+    fn second_price(&self, auction_id: u64) -> SingleValueMapper<bool>;
+
+    #[view(getAuctionSecondHighestBid)]
+    #[storage_mapper("secondHighestBid")]
+    fn second_highest_bid(&self, auction_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[view(getAuctionMinBidDiffPercentage)]
+    #[storage_mapper("minBidDiffPercentage")]
+    fn min_bid_diff_percentage(&self, auction_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getAuctionBuyNowPrice)]
+    #[storage_mapper("buyNowPrice")]
+    fn buy_now_price(&self, auction_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("listingPrices")]
+    fn listing_prices(
+        &self,
+        auction_id: u64,
+    ) -> MapMapper<(EgldOrEsdtTokenIdentifier, u64), BigUint>;
 
     #[view(getLastValidAuctionId)]
     #[storage_mapper("lastValidAuctionId")]
@@ -1,6 +1,10 @@
 multiversx_sc::imports!();
 
-use crate::auction::{Auction, AuctionType, NFT_AMOUNT};
+use crate::{
+    auction::{Auction, AuctionType, ListingCloseReason, NFT_AMOUNT},
+    sealed_bid::{SealedBid, MAX_SEALED_BIDS},
+    uniform_price::UniformPriceBid,
+};
 
 #[multiversx_sc::module]
 pub trait BiddingModule:
@@ -9,6 +13,10 @@ pub trait BiddingModule:
     + crate::token_distribution::TokenDistributionModule
     + crate::token_whitelist::TokenWhitelistModule
     + crate::common_util_functions::CommonUtilFunctions
+    + crate::soft_close::SoftCloseModule
+    + crate::sealed_bid::SealedBidModule
+    + crate::proxy_bid::ProxyBidModule
+    + crate::uniform_price::UniformPriceModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[payable("*")]
@@ -17,11 +25,12 @@ pub trait BiddingModule:
         self.require_not_paused();
 
         let (payment_token, payment_token_nonce, payment_amount) =
-            self.call_value().egld_or_single_esdt().into_tuple();
-        let mut auction = self.try_get_auction(auction_id);
+            self.get_bid_payment(auction_id).into_tuple();
+        let auction = self.try_get_auction(auction_id);
         let caller = self.blockchain().get_caller();
 
         self.common_bid_checks(
+            auction_id,
             &auction,
             &nft_type,
             nft_nonce,
@@ -30,60 +39,192 @@ pub trait BiddingModule:
         );
 
         require!(
-            auction.auction_type != AuctionType::SftOnePerPayment,
+            matches!(
+                auction.auction_type,
+                AuctionType::Nft | AuctionType::SftAll | AuctionType::Bundle
+            ),
             "Cannot bid on this type of auction"
         );
         require!(auction.current_winner != caller, "Can't outbid yourself");
+        self.require_valid_bid_amount(auction_id, &auction, &payment_amount);
+
+        self.place_bid(auction_id, auction, caller, payment_amount, false);
+    }
+
+    #[payable("*")]
+    #[endpoint(proxyBid)]
+    fn proxy_bid(&self, auction_id: u64, nft_type: TokenIdentifier, nft_nonce: u64) {
+        self.require_not_paused();
+
+        let (payment_token, payment_token_nonce, payment_amount) =
+            self.get_bid_payment(auction_id).into_tuple();
+        let auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+
+        self.common_bid_checks(
+            auction_id,
+            &auction,
+            &nft_type,
+            nft_nonce,
+            &payment_token,
+            payment_token_nonce,
+        );
+
         require!(
-            payment_amount >= auction.min_bid,
+            matches!(
+                auction.auction_type,
+                AuctionType::Nft | AuctionType::SftAll | AuctionType::Bundle
+            ),
+            "Cannot bid on this type of auction"
+        );
+
+        // the current winner can raise their maximum without changing the current bid
+        if auction.current_winner == caller {
+            let new_max_bid = self.get_winner_max_bid(auction_id, &auction) + payment_amount;
+            if let Some(max_bid) = &auction.max_bid {
+                require!(
+                    &new_max_bid <= max_bid,
+                    "Bid must be less than or equal to the max bid"
+                );
+            }
+
+            self.proxy_max_bid(auction_id).set(&new_max_bid);
+            return;
+        }
+
+        self.require_valid_bid_amount(auction_id, &auction, &payment_amount);
+
+        self.place_bid(auction_id, auction, caller, payment_amount, true);
+    }
+
+    fn require_valid_bid_amount(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        bid_amount: &BigUint,
+    ) {
+        require!(
+            bid_amount >= &auction.min_bid,
             "Bid must be higher than or equal to the min bid"
         );
         require!(
-            payment_amount > auction.current_bid,
+            bid_amount > &auction.current_bid,
             "Bid must be higher than the current winning bid"
         );
 
-        let mut max_bid_reached = false;
         if let Some(max_bid) = &auction.max_bid {
             require!(
-                &payment_amount <= max_bid,
+                bid_amount <= max_bid,
                 "Bid must be less than or equal to the max bid"
             );
-
-            max_bid_reached = &payment_amount == max_bid;
         }
 
-        if auction.current_bid > 0 {
-            if let Some(max_bid) = &auction.max_bid {
-                if &payment_amount < max_bid {
-                    require!(
-                        (&payment_amount - &auction.current_bid) >= auction.min_bid_diff,
-                        "The difference from the last bid must be higher"
-                    );
-                }
-            }
+        // bids reaching the max bid are always accepted
+        let is_max_bid = match &auction.max_bid {
+            Some(max_bid) => bid_amount == max_bid,
+            None => false,
+        };
+        if auction.current_bid > 0 && !is_max_bid {
+            require!(
+                (bid_amount - &auction.current_bid)
+                    >= self.get_min_bid_diff(auction_id, auction, &auction.current_bid),
+                "The difference from the last bid must be higher"
+            );
         }
+    }
 
-        // refund losing bid
-        if auction.current_winner != ManagedAddress::zero() {
+    // for proxy bids, `bid_amount` is the bidder's maximum, which is escrowed entirely
+    fn place_bid(
+        &self,
+        auction_id: u64,
+        mut auction: Auction<Self::Api>,
+        bidder: ManagedAddress,
+        bid_amount: BigUint,
+        is_proxy: bool,
+    ) {
+        let winner_max_bid = self.get_winner_max_bid(auction_id, &auction);
+        let has_winner = !auction.current_winner.is_zero();
+        let second_price = self.second_price(auction_id).get();
+
+        if has_winner && winner_max_bid >= bid_amount {
+            // the proxy bid of the current winner automatically outbids the new bid
             self.transfer_or_save_payment(
-                &auction.current_winner,
+                &bidder,
                 &auction.payment_token,
                 auction.payment_nonce,
-                &auction.current_bid,
+                &bid_amount,
             );
+
+            let outbid_amount =
+                &bid_amount + &self.get_min_bid_diff(auction_id, &auction, &bid_amount);
+            if second_price {
+                self.second_highest_bid(auction_id).set(&bid_amount);
+            }
+            auction.current_bid = core::cmp::min(winner_max_bid, outbid_amount);
+        } else {
+            // refund losing bid
+            if has_winner {
+                self.transfer_or_save_payment(
+                    &auction.current_winner,
+                    &auction.payment_token,
+                    auction.payment_nonce,
+                    &winner_max_bid,
+                );
+            }
+
+            // proxy bids only go as high as needed to beat the previous winner
+            let new_bid = if !is_proxy {
+                bid_amount.clone()
+            } else if has_winner {
+                core::cmp::min(
+                    bid_amount.clone(),
+                    &winner_max_bid + &self.get_min_bid_diff(auction_id, &auction, &winner_max_bid),
+                )
+            } else {
+                auction.min_bid.clone()
+            };
+
+            // update auction bid and winner
+            if second_price {
+                self.second_highest_bid(auction_id).set(&winner_max_bid);
+            }
+            auction.current_bid = new_bid;
+            auction.current_winner = bidder;
+
+            if is_proxy {
+                self.proxy_max_bid(auction_id).set(&bid_amount);
+            } else {
+                self.proxy_max_bid(auction_id).clear();
+            }
         }
 
-        // update auction bid and winner
-        auction.current_bid = payment_amount;
-        auction.current_winner = caller;
+        // the buy-now price is only available until the first bid
+        self.buy_now_price(auction_id).clear();
+
+        let max_bid_reached = match &auction.max_bid {
+            Some(max_bid) => &auction.current_bid == max_bid,
+            None => false,
+        };
+
+        // start the countdown on the first bid of a reserve auction,
+        // or push the deadline forward in case of a late bid
+        let deadline_extended = if auction.deadline == 0 {
+            let current_time = self.blockchain().get_block_timestamp();
+            auction.deadline = current_time + self.countdown_duration(auction_id).get();
+            true
+        } else {
+            !max_bid_reached && self.try_extend_deadline(auction_id, &mut auction)
+        };
         self.auction_by_id(auction_id).set(&auction);
 
         self.emit_bid_event(auction_id, auction.clone());
+        if deadline_extended {
+            self.emit_extend_deadline_event(auction_id, auction.clone());
+        }
 
         // end auction in case the max bid has been reached
         if max_bid_reached && auction.auction_type != AuctionType::SftOnePerPayment {
-            self.end_auction_common(auction_id, auction);
+            self.end_auction_common(auction_id, auction, None);
         }
     }
 
@@ -99,7 +240,7 @@ pub trait BiddingModule:
         self.require_not_paused();
 
         let (payment_token, payment_token_nonce, payment_amount) =
-            self.call_value().egld_or_single_esdt().into_tuple();
+            self.get_bid_payment(auction_id).into_tuple();
         let mut auction = self.try_get_auction(auction_id);
         let caller = self.blockchain().get_caller();
 
@@ -107,9 +248,9 @@ pub trait BiddingModule:
             OptionalValue::Some(amt) => amt,
             OptionalValue::None => BigUint::from(NFT_AMOUNT),
         };
-        let sft_total_value = &sft_buy_amount * &auction.min_bid;
 
         self.common_bid_checks(
+            auction_id,
             &auction,
             &nft_type,
             nft_nonce,
@@ -117,6 +258,11 @@ pub trait BiddingModule:
             payment_token_nonce,
         );
 
+        let price = self
+            .find_listing_price(auction_id, &auction, &payment_token, payment_token_nonce)
+            .unwrap_or_default();
+        let sft_total_value = &sft_buy_amount * &price;
+
         require!(sft_buy_amount > 0, "Must buy more than 0");
         require!(
             auction.auction_type == AuctionType::SftOnePerPayment,
@@ -133,11 +279,17 @@ pub trait BiddingModule:
 
         auction.current_winner = caller;
         auction.current_bid = payment_amount;
-        self.distribute_tokens_after_auction_end(&auction, Some(&sft_buy_amount));
+
+        // the sale is settled in the token that was actually paid
+        let mut sale = auction.clone();
+        sale.payment_token = payment_token;
+        sale.payment_nonce = payment_token_nonce;
+        self.distribute_tokens_after_auction_end(auction_id, &sale, Some(&sft_buy_amount), None);
 
         auction.auctioned_tokens.amount -= &sft_buy_amount;
         if auction.auctioned_tokens.amount == 0 {
-            self.auction_by_id(auction_id).clear();
+            self.clear_auction(auction_id);
+            self.emit_close_listing_event(auction_id, auction.clone(), ListingCloseReason::SoldOut);
         } else {
             self.auction_by_id(auction_id).set(&auction);
         }
@@ -145,8 +297,356 @@ pub trait BiddingModule:
         self.emit_buy_sft_event(auction_id, auction, sft_buy_amount);
     }
 
+    #[payable("*")]
+    #[endpoint(buyDutchAuction)]
+    fn buy_dutch_auction(&self, auction_id: u64, nft_type: TokenIdentifier, nft_nonce: u64) {
+        self.require_not_paused();
+
+        let (payment_token, payment_token_nonce, payment_amount) =
+            self.get_bid_payment(auction_id).into_tuple();
+        let mut auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+
+        self.common_bid_checks(
+            auction_id,
+            &auction,
+            &nft_type,
+            nft_nonce,
+            &payment_token,
+            payment_token_nonce,
+        );
+
+        require!(
+            auction.auction_type == AuctionType::Dutch,
+            "Cannot buy this type of auction"
+        );
+
+        let current_price = self.calculate_dutch_price(&auction);
+        require!(
+            payment_amount >= current_price,
+            "Payment must be higher than or equal to the current price"
+        );
+
+        // refund the amount paid above the current price
+        let excess_amount = &payment_amount - &current_price;
+        self.transfer_or_save_payment(&caller, &payment_token, payment_token_nonce, &excess_amount);
+
+        auction.current_bid = current_price;
+        auction.current_winner = caller;
+
+        self.emit_bid_event(auction_id, auction.clone());
+        self.end_auction_common(auction_id, auction, None);
+    }
+
+    #[payable("*")]
+    #[endpoint(buyNft)]
+    fn buy_nft(&self, auction_id: u64, nft_type: TokenIdentifier, nft_nonce: u64) {
+        self.require_not_paused();
+
+        let (payment_token, payment_token_nonce, payment_amount) =
+            self.get_bid_payment(auction_id).into_tuple();
+        let mut auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+
+        self.common_bid_checks(
+            auction_id,
+            &auction,
+            &nft_type,
+            nft_nonce,
+            &payment_token,
+            payment_token_nonce,
+        );
+
+        require!(
+            auction.auction_type == AuctionType::FixedPrice,
+            "Cannot buy this type of auction"
+        );
+        let price = self
+            .find_listing_price(auction_id, &auction, &payment_token, payment_token_nonce)
+            .unwrap_or_default();
+        require!(
+            payment_amount == price,
+            "Wrong amount paid, must pay equal to the selling price"
+        );
+
+        auction.payment_token = payment_token;
+        auction.payment_nonce = payment_token_nonce;
+        auction.current_bid = payment_amount;
+        auction.current_winner = caller;
+
+        self.emit_bid_event(auction_id, auction.clone());
+        self.end_auction_common(auction_id, auction, None);
+    }
+
+    #[payable("*")]
+    #[endpoint(buyNow)]
+    fn buy_now(&self, auction_id: u64, nft_type: TokenIdentifier, nft_nonce: u64) {
+        self.require_not_paused();
+
+        let (payment_token, payment_token_nonce, payment_amount) =
+            self.get_bid_payment(auction_id).into_tuple();
+        let mut auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+
+        self.common_bid_checks(
+            auction_id,
+            &auction,
+            &nft_type,
+            nft_nonce,
+            &payment_token,
+            payment_token_nonce,
+        );
+
+        let buy_now_price_mapper = self.buy_now_price(auction_id);
+        require!(
+            !buy_now_price_mapper.is_empty(),
+            "The auction has no buy-now price"
+        );
+        require!(
+            payment_amount == buy_now_price_mapper.get(),
+            "Wrong amount paid, must pay equal to the buy-now price"
+        );
+
+        auction.current_bid = payment_amount;
+        auction.current_winner = caller;
+        // the buy-now price is paid in full, regardless of second price settlement
+        self.second_price(auction_id).clear();
+
+        self.emit_bid_event(auction_id, auction.clone());
+        self.end_auction_common(auction_id, auction, None);
+    }
+
+    #[payable("*")]
+    #[endpoint(sealedBid)]
+    fn sealed_bid(
+        &self,
+        auction_id: u64,
+        nft_type: TokenIdentifier,
+        nft_nonce: u64,
+        commitment: ManagedByteArray<Self::Api, 32>,
+    ) {
+        self.require_not_paused();
+
+        let (payment_token, payment_token_nonce, payment_amount) =
+            self.get_bid_payment(auction_id).into_tuple();
+        let auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+
+        self.common_bid_checks(
+            auction_id,
+            &auction,
+            &nft_type,
+            nft_nonce,
+            &payment_token,
+            payment_token_nonce,
+        );
+
+        require!(
+            auction.auction_type == AuctionType::SealedBid,
+            "Cannot place sealed bids on this type of auction"
+        );
+        require!(
+            payment_amount >= auction.min_bid,
+            "Deposit must be higher than or equal to the min bid"
+        );
+
+        let mut sealed_bids_mapper = self.sealed_bids(auction_id);
+        require!(
+            !sealed_bids_mapper.contains_key(&caller),
+            "Already placed a sealed bid"
+        );
+        require!(
+            sealed_bids_mapper.len() < MAX_SEALED_BIDS,
+            "Too many bids placed on this auction"
+        );
+
+        let sealed_bid = SealedBid {
+            commitment,
+            deposit: payment_amount.clone(),
+        };
+        sealed_bids_mapper.insert(caller.clone(), sealed_bid);
+
+        self.emit_sealed_bid_event(auction_id, auction, &caller, &payment_amount);
+    }
+
+    #[endpoint(revealBid)]
+    fn reveal_bid(&self, auction_id: u64, bid_amount: BigUint, salt: ManagedBuffer) {
+        self.require_not_paused();
+
+        let mut auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+        let current_time = self.blockchain().get_block_timestamp();
+
+        require!(
+            auction.auction_type == AuctionType::SealedBid,
+            "Cannot reveal bids on this type of auction"
+        );
+        require!(
+            current_time >= auction.deadline
+                && current_time <= self.reveal_deadline(auction_id).get(),
+            "Not in the reveal phase"
+        );
+
+        let opt_sealed_bid = self.sealed_bids(auction_id).remove(&caller);
+        require!(opt_sealed_bid.is_some(), "No sealed bid to reveal");
+
+        let sealed_bid = unsafe { opt_sealed_bid.unwrap_unchecked() };
+        require!(
+            self.compute_bid_commitment(auction_id, &caller, &bid_amount, &salt)
+                == sealed_bid.commitment,
+            "Bid does not match the commitment"
+        );
+
+        if bid_amount > sealed_bid.deposit {
+            // under-collateralized bids are treated the same as unrevealed ones
+            self.refund_or_slash_sealed_bid(&auction, &caller, &sealed_bid.deposit);
+        } else if bid_amount >= auction.min_bid && bid_amount > auction.current_bid {
+            // refund previous winning bid
+            if !auction.current_winner.is_zero() {
+                self.transfer_or_save_payment(
+                    &auction.current_winner,
+                    &auction.payment_token,
+                    auction.payment_nonce,
+                    &auction.current_bid,
+                );
+            }
+
+            // refund the deposit exceeding the bid
+            self.transfer_or_save_payment(
+                &caller,
+                &auction.payment_token,
+                auction.payment_nonce,
+                &(&sealed_bid.deposit - &bid_amount),
+            );
+
+            if self.second_price(auction_id).get() {
+                self.second_highest_bid(auction_id)
+                    .set(&auction.current_bid);
+            }
+            auction.current_bid = bid_amount.clone();
+            auction.current_winner = caller.clone();
+            self.auction_by_id(auction_id).set(&auction);
+        } else {
+            let second_highest_bid_mapper = self.second_highest_bid(auction_id);
+            if self.second_price(auction_id).get()
+                && bid_amount >= auction.min_bid
+                && bid_amount > second_highest_bid_mapper.get()
+            {
+                second_highest_bid_mapper.set(&bid_amount);
+            }
+
+            self.transfer_or_save_payment(
+                &caller,
+                &auction.payment_token,
+                auction.payment_nonce,
+                &sealed_bid.deposit,
+            );
+        }
+
+        self.emit_reveal_bid_event(auction_id, auction, &caller, &bid_amount);
+    }
+
+    #[payable("*")]
+    #[endpoint(uniformPriceBid)]
+    fn uniform_price_bid(
+        &self,
+        auction_id: u64,
+        nft_type: TokenIdentifier,
+        nft_nonce: u64,
+        quantity: BigUint,
+        unit_price: BigUint,
+    ) {
+        self.require_not_paused();
+
+        let (payment_token, payment_token_nonce, payment_amount) =
+            self.get_bid_payment(auction_id).into_tuple();
+        let auction = self.try_get_auction(auction_id);
+        let caller = self.blockchain().get_caller();
+
+        self.common_bid_checks(
+            auction_id,
+            &auction,
+            &nft_type,
+            nft_nonce,
+            &payment_token,
+            payment_token_nonce,
+        );
+
+        require!(
+            auction.auction_type == AuctionType::UniformPrice,
+            "Cannot place uniform price bids on this type of auction"
+        );
+        require!(
+            quantity > 0 && quantity <= auction.auctioned_tokens.amount,
+            "Invalid quantity"
+        );
+        require!(
+            quantity >= self.uniform_price_min_quantity(auction_id).get(),
+            "Quantity is below the minimum"
+        );
+        require!(
+            unit_price >= auction.min_bid,
+            "Unit price must be higher than or equal to the min bid"
+        );
+        require!(
+            payment_amount == &quantity * &unit_price,
+            "Payment must be equal to the quantity times the unit price"
+        );
+
+        self.insert_uniform_price_bid(
+            auction_id,
+            &auction,
+            UniformPriceBid {
+                bidder: caller.clone(),
+                quantity: quantity.clone(),
+                unit_price: unit_price.clone(),
+            },
+        );
+
+        self.emit_uniform_price_bid_event(auction_id, auction, &caller, &quantity, &unit_price);
+    }
+
+    // the required token of a private listing can't be checked through the
+    // buyer's balance from another shard, so holders send it along with the
+    // payment and get it back right away
+    fn get_bid_payment(&self, auction_id: u64) -> EgldOrEsdtTokenPayment<Self::Api> {
+        let caller = self.blockchain().get_caller();
+        let transfers = self.call_value().all_esdt_transfers();
+        if transfers.len() <= 1 {
+            require!(
+                self.is_allowed_buyer(auction_id, &caller, None),
+                "Only allowed buyers can bid on this auction"
+            );
+            return self.call_value().egld_or_single_esdt();
+        }
+
+        require!(
+            transfers.len() == 2,
+            "Only the payment and the required token can be sent"
+        );
+        let payment = transfers.get(0);
+        let presented_token = transfers.get(1);
+        require!(
+            self.is_allowed_buyer(auction_id, &caller, Some(&presented_token)),
+            "Only allowed buyers can bid on this auction"
+        );
+        self.send().direct_esdt(
+            &caller,
+            &presented_token.token_identifier,
+            presented_token.token_nonce,
+            &presented_token.amount,
+        );
+
+        EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier),
+            payment.token_nonce,
+            payment.amount,
+        )
+    }
+
     fn common_bid_checks(
         &self,
+        auction_id: u64,
         auction: &Auction<Self::Api>,
         nft_type: &TokenIdentifier,
         nft_nonce: u64,
@@ -169,9 +669,13 @@ pub trait BiddingModule:
             current_time >= auction.start_time,
             "Auction hasn't started yet"
         );
-        require!(current_time < auction.deadline, "Auction ended already");
         require!(
-            payment_token == &auction.payment_token && payment_nonce == auction.payment_nonce,
+            auction.deadline == 0 || current_time < auction.deadline,
+            "Auction ended already"
+        );
+        require!(
+            self.find_listing_price(auction_id, auction, payment_token, payment_nonce)
+                .is_some(),
             "Wrong token used as payment"
         );
     }
@@ -1,8 +1,9 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use super::auction::{Auction, AuctionType};
-use crate::offer::Offer;
+use super::auction::{Auction, AuctionType, ListingCloseReason};
+use crate::offer::{CounterOffer, Offer};
+use crate::uniform_price::UniformPriceSettlement;
 
 #[allow(clippy::too_many_arguments)]
 #[multiversx_sc::module]
@@ -25,6 +26,70 @@ pub trait EventsModule {
         )
     }
 
+    fn emit_auction_bundle_event(
+        self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        bundle_weights: &ManagedVec<u64>,
+        bundled_tokens: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) {
+        self.auction_bundle_event(
+            auction_id,
+            &auction.original_owner,
+            bundle_weights,
+            bundled_tokens,
+        )
+    }
+
+    fn emit_update_auction_event(
+        self,
+        auction_id: u64,
+        old_auction: Auction<Self::Api>,
+        new_auction: Auction<Self::Api>,
+        old_min_bid_diff_percentage: u64,
+        new_min_bid_diff_percentage: u64,
+    ) {
+        self.update_auction_event(
+            &new_auction.auctioned_tokens.token_identifier,
+            new_auction.auctioned_tokens.token_nonce,
+            auction_id,
+            &new_auction.original_owner,
+            &old_auction.min_bid,
+            &new_auction.min_bid,
+            &old_auction.max_bid.unwrap_or_else(BigUint::zero),
+            &new_auction.max_bid.unwrap_or_else(BigUint::zero),
+            &old_auction.min_bid_diff,
+            &new_auction.min_bid_diff,
+            old_min_bid_diff_percentage,
+            new_min_bid_diff_percentage,
+            old_auction.deadline,
+            new_auction.deadline,
+            old_auction.payment_token,
+            new_auction.payment_token,
+            old_auction.payment_nonce,
+            new_auction.payment_nonce,
+        )
+    }
+
+    fn emit_listing_price_event(
+        self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        payment_token: EgldOrEsdtTokenIdentifier,
+        payment_nonce: u64,
+        price: BigUint,
+    ) {
+        self.listing_price_event(
+            &auction.auctioned_tokens.token_identifier,
+            auction.auctioned_tokens.token_nonce,
+            auction_id,
+            &auction.original_owner,
+            payment_token,
+            payment_nonce,
+            &price,
+        )
+    }
+
     fn emit_bid_event(self, auction_id: u64, auction: Auction<Self::Api>) {
         self.bid_event(
             &auction.auctioned_tokens.token_identifier,
@@ -36,6 +101,84 @@ pub trait EventsModule {
         );
     }
 
+    fn emit_extend_deadline_event(self, auction_id: u64, auction: Auction<Self::Api>) {
+        self.extend_deadline_event(
+            &auction.auctioned_tokens.token_identifier,
+            auction.auctioned_tokens.token_nonce,
+            auction_id,
+            &auction.current_winner,
+            &auction.current_bid,
+            auction.deadline,
+        );
+    }
+
+    fn emit_sealed_bid_event(
+        self,
+        auction_id: u64,
+        auction: Auction<Self::Api>,
+        bidder: &ManagedAddress,
+        deposit: &BigUint,
+    ) {
+        self.sealed_bid_event(
+            &auction.auctioned_tokens.token_identifier,
+            auction.auctioned_tokens.token_nonce,
+            auction_id,
+            bidder,
+            deposit,
+        );
+    }
+
+    fn emit_reveal_bid_event(
+        self,
+        auction_id: u64,
+        auction: Auction<Self::Api>,
+        bidder: &ManagedAddress,
+        bid_amount: &BigUint,
+    ) {
+        self.reveal_bid_event(
+            &auction.auctioned_tokens.token_identifier,
+            auction.auctioned_tokens.token_nonce,
+            auction_id,
+            bidder,
+            bid_amount,
+            &auction.current_winner,
+            &auction.current_bid,
+        );
+    }
+
+    fn emit_uniform_price_bid_event(
+        self,
+        auction_id: u64,
+        auction: Auction<Self::Api>,
+        bidder: &ManagedAddress,
+        quantity: &BigUint,
+        unit_price: &BigUint,
+    ) {
+        self.uniform_price_bid_event(
+            &auction.auctioned_tokens.token_identifier,
+            auction.auctioned_tokens.token_nonce,
+            auction_id,
+            bidder,
+            quantity,
+            unit_price,
+        );
+    }
+
+    fn emit_end_uniform_price_auction_event(
+        self,
+        auction_id: u64,
+        auction: Auction<Self::Api>,
+        settlement: UniformPriceSettlement<Self::Api>,
+    ) {
+        self.end_uniform_price_auction_event(
+            &auction.auctioned_tokens.token_identifier,
+            auction.auctioned_tokens.token_nonce,
+            auction_id,
+            &settlement.nr_sold_tokens,
+            &settlement.clearing_price,
+        );
+    }
+
     fn emit_end_auction_event(self, auction_id: u64, auction: Auction<Self::Api>) {
         self.end_auction_event(
             &auction.auctioned_tokens.token_identifier,
@@ -63,6 +206,39 @@ pub trait EventsModule {
         );
     }
 
+    fn emit_accept_offer_on_listing_event(
+        self,
+        auction_id: u64,
+        offer_id: u64,
+        auction: &Auction<Self::Api>,
+        nr_sold_tokens: &BigUint,
+    ) {
+        self.accept_offer_on_listing_event(
+            &auction.auctioned_tokens.token_identifier,
+            auction.auctioned_tokens.token_nonce,
+            auction_id,
+            offer_id,
+            nr_sold_tokens,
+            &auction.auctioned_tokens.amount,
+        );
+    }
+
+    fn emit_close_listing_event(
+        self,
+        auction_id: u64,
+        auction: Auction<Self::Api>,
+        reason: ListingCloseReason,
+    ) {
+        self.close_listing_event(
+            &auction.auctioned_tokens.token_identifier,
+            auction.auctioned_tokens.token_nonce,
+            auction_id,
+            &auction.original_owner,
+            &auction.auctioned_tokens.amount,
+            reason,
+        );
+    }
+
     fn emit_withdraw_event(self, auction_id: u64, auction: Auction<Self::Api>) {
         self.withdraw_event(
             &auction.auctioned_tokens.token_identifier,
@@ -88,6 +264,72 @@ pub trait EventsModule {
         )
     }
 
+    fn emit_offer_traits_event(
+        self,
+        offer_id: u64,
+        offer: &Offer<Self::Api>,
+        required_traits: &ManagedVec<ManagedBuffer>,
+    ) {
+        self.offer_traits_event(
+            offer_id,
+            &offer.offer_token.token_identifier,
+            &offer.offer_owner,
+            required_traits,
+        )
+    }
+
+    fn emit_offer_barter_tokens_event(
+        self,
+        offer_id: u64,
+        offer: &Offer<Self::Api>,
+        barter_tokens: &ManagedVec<EsdtTokenPayment>,
+    ) {
+        self.offer_barter_tokens_event(
+            offer_id,
+            &offer.offer_token.token_identifier,
+            offer.offer_token.token_nonce,
+            &offer.offer_owner,
+            barter_tokens,
+        )
+    }
+
+    fn emit_counter_offer_event(
+        self,
+        offer_id: u64,
+        offer: &Offer<Self::Api>,
+        counter_offer: &CounterOffer<Self::Api>,
+    ) {
+        self.counter_offer_event(
+            offer_id,
+            &counter_offer.offer_token.token_identifier,
+            counter_offer.offer_token.token_nonce,
+            &counter_offer.offer_token.amount,
+            &offer.payment.token_identifier,
+            offer.payment.token_nonce,
+            &offer.payment.amount,
+            &counter_offer.price,
+            &offer.offer_owner,
+            &counter_offer.seller,
+            counter_offer.deadline,
+        )
+    }
+
+    fn emit_withdraw_counter_offer_event(
+        self,
+        offer_id: u64,
+        counter_offer: &CounterOffer<Self::Api>,
+        caller: &ManagedAddress,
+    ) {
+        self.withdraw_counter_offer_event(
+            offer_id,
+            &counter_offer.offer_token.token_identifier,
+            counter_offer.offer_token.token_nonce,
+            &counter_offer.price,
+            &counter_offer.seller,
+            caller,
+        )
+    }
+
     fn emit_withdraw_offer_event(self, offer_id: u64, offer: Offer<Self::Api>) {
         self.withdraw_offer_token_event(
             offer_id,
@@ -103,6 +345,64 @@ pub trait EventsModule {
         )
     }
 
+    fn emit_update_offer_event(
+        self,
+        offer_id: u64,
+        old_offer: Offer<Self::Api>,
+        new_offer: Offer<Self::Api>,
+    ) {
+        self.update_offer_event(
+            offer_id,
+            &new_offer.offer_token.token_identifier,
+            new_offer.offer_token.token_nonce,
+            &new_offer.offer_token.amount,
+            &new_offer.payment.token_identifier,
+            new_offer.payment.token_nonce,
+            &old_offer.payment.amount,
+            &new_offer.payment.amount,
+            old_offer.deadline,
+            new_offer.deadline,
+            &new_offer.offer_owner,
+        )
+    }
+
+    fn emit_reject_offer_event(
+        self,
+        offer_id: u64,
+        auction_id: u64,
+        offer: &Offer<Self::Api>,
+        seller: &ManagedAddress,
+        reason: u32,
+    ) {
+        self.reject_offer_event(
+            offer_id,
+            auction_id,
+            &offer.offer_token.token_identifier,
+            offer.offer_token.token_nonce,
+            &offer.offer_owner,
+            seller,
+            reason,
+        )
+    }
+
+    fn emit_expired_offer_refund_event(
+        self,
+        offer_id: u64,
+        offer: &Offer<Self::Api>,
+        bounty_amount: &BigUint,
+        caller: &ManagedAddress,
+    ) {
+        self.expired_offer_refund_event(
+            offer_id,
+            &offer.payment.token_identifier,
+            offer.payment.token_nonce,
+            &offer.payment.amount,
+            &offer.offer_owner,
+            bounty_amount,
+            caller,
+        )
+    }
+
     fn emit_accept_offer_event(
         self,
         offer_id: u64,
@@ -142,6 +442,50 @@ pub trait EventsModule {
         creator_royalties_percentage: BigUint, // between 0 and 10,000
     );
 
+    #[event("auction_bundle_event")]
+    fn auction_bundle_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] bundle_weights: &ManagedVec<u64>,
+        bundled_tokens: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+    );
+
+    #[event("update_auction_event")]
+    fn update_auction_event(
+        &self,
+        #[indexed] auction_token_id: &TokenIdentifier,
+        #[indexed] auctioned_token_nonce: u64,
+        #[indexed] auction_id: u64,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] old_min_bid: &BigUint,
+        #[indexed] new_min_bid: &BigUint,
+        #[indexed] old_max_bid: &BigUint,
+        #[indexed] new_max_bid: &BigUint,
+        #[indexed] old_min_bid_diff: &BigUint,
+        #[indexed] new_min_bid_diff: &BigUint,
+        #[indexed] old_min_bid_diff_percentage: u64,
+        #[indexed] new_min_bid_diff_percentage: u64,
+        #[indexed] old_deadline: u64,
+        #[indexed] new_deadline: u64,
+        #[indexed] old_accepted_payment_token: EgldOrEsdtTokenIdentifier,
+        #[indexed] new_accepted_payment_token: EgldOrEsdtTokenIdentifier,
+        #[indexed] old_accepted_payment_token_nonce: u64,
+        #[indexed] new_accepted_payment_token_nonce: u64,
+    );
+
+    #[event("listing_price_event")]
+    fn listing_price_event(
+        &self,
+        #[indexed] auction_token_id: &TokenIdentifier,
+        #[indexed] auctioned_token_nonce: u64,
+        #[indexed] auction_id: u64,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] accepted_payment_token: EgldOrEsdtTokenIdentifier,
+        #[indexed] accepted_payment_token_nonce: u64,
+        #[indexed] price: &BigUint,
+    );
+
     #[event("bid_event")]
     fn bid_event(
         &self,
@@ -153,6 +497,60 @@ pub trait EventsModule {
         #[indexed] bid_amount: &BigUint,
     );
 
+    #[event("extend_deadline_event")]
+    fn extend_deadline_event(
+        &self,
+        #[indexed] auction_token_id: &TokenIdentifier,
+        #[indexed] auctioned_token_nonce: u64,
+        #[indexed] auction_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        #[indexed] bid_amount: &BigUint,
+        #[indexed] new_deadline: u64,
+    );
+
+    #[event("sealed_bid_event")]
+    fn sealed_bid_event(
+        &self,
+        #[indexed] auction_token_id: &TokenIdentifier,
+        #[indexed] auctioned_token_nonce: u64,
+        #[indexed] auction_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        #[indexed] deposit_amount: &BigUint,
+    );
+
+    #[event("reveal_bid_event")]
+    fn reveal_bid_event(
+        &self,
+        #[indexed] auction_token_id: &TokenIdentifier,
+        #[indexed] auctioned_token_nonce: u64,
+        #[indexed] auction_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        #[indexed] bid_amount: &BigUint,
+        #[indexed] current_winner: &ManagedAddress,
+        #[indexed] winning_bid_amount: &BigUint,
+    );
+
+    #[event("uniform_price_bid_event")]
+    fn uniform_price_bid_event(
+        &self,
+        #[indexed] auction_token_id: &TokenIdentifier,
+        #[indexed] auctioned_token_nonce: u64,
+        #[indexed] auction_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        #[indexed] quantity: &BigUint,
+        #[indexed] unit_price: &BigUint,
+    );
+
+    #[event("end_uniform_price_auction_event")]
+    fn end_uniform_price_auction_event(
+        &self,
+        #[indexed] auction_token_id: &TokenIdentifier,
+        #[indexed] auctioned_token_nonce: u64,
+        #[indexed] auction_id: u64,
+        #[indexed] nr_sold_tokens: &BigUint,
+        #[indexed] clearing_price: &BigUint,
+    );
+
     #[event("end_auction_event")]
     fn end_auction_event(
         &self,
@@ -175,6 +573,28 @@ pub trait EventsModule {
         #[indexed] bid_sft_amount: &BigUint,
     );
 
+    #[event("accept_offer_on_listing_event")]
+    fn accept_offer_on_listing_event(
+        &self,
+        #[indexed] auction_token_id: &TokenIdentifier,
+        #[indexed] auctioned_token_nonce: u64,
+        #[indexed] auction_id: u64,
+        #[indexed] offer_id: u64,
+        #[indexed] nr_sold_tokens: &BigUint,
+        #[indexed] nr_remaining_tokens: &BigUint,
+    );
+
+    #[event("close_listing_event")]
+    fn close_listing_event(
+        &self,
+        #[indexed] auction_token_id: &TokenIdentifier,
+        #[indexed] auctioned_token_nonce: u64,
+        #[indexed] auction_id: u64,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] nr_unsold_tokens: &BigUint,
+        #[indexed] reason: ListingCloseReason,
+    );
+
     #[event("withdraw_event")]
     fn withdraw_event(
         &self,
@@ -200,6 +620,52 @@ pub trait EventsModule {
         #[indexed] deadline: u64,
     );
 
+    #[event("offer_traits_event")]
+    fn offer_traits_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] offer_token_id: &TokenIdentifier,
+        #[indexed] buyer: &ManagedAddress,
+        required_traits: &ManagedVec<ManagedBuffer>,
+    );
+
+    #[event("offer_barter_tokens_event")]
+    fn offer_barter_tokens_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] offer_token_id: &TokenIdentifier,
+        #[indexed] offer_token_nonce: u64,
+        #[indexed] buyer: &ManagedAddress,
+        barter_tokens: &ManagedVec<EsdtTokenPayment>,
+    );
+
+    #[event("counter_offer_event")]
+    fn counter_offer_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] offer_token_id: &TokenIdentifier,
+        #[indexed] offer_token_nonce: u64,
+        #[indexed] offer_amount: &BigUint,
+        #[indexed] payment_token_type: &EgldOrEsdtTokenIdentifier,
+        #[indexed] payment_token_nonce: u64,
+        #[indexed] payment_amount: &BigUint,
+        #[indexed] counter_price: &BigUint,
+        #[indexed] buyer: &ManagedAddress,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] deadline: u64,
+    );
+
+    #[event("withdraw_counter_offer_event")]
+    fn withdraw_counter_offer_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] offer_token_id: &TokenIdentifier,
+        #[indexed] offer_token_nonce: u64,
+        #[indexed] counter_price: &BigUint,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] caller: &ManagedAddress,
+    );
+
     #[event("withdraw_offer_token_event")]
     fn withdraw_offer_token_event(
         &self,
@@ -215,6 +681,46 @@ pub trait EventsModule {
         #[indexed] deadline: u64,
     );
 
+    #[event("update_offer_event")]
+    fn update_offer_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] offer_token_id: &TokenIdentifier,
+        #[indexed] offer_token_nonce: u64,
+        #[indexed] offer_amount: &BigUint,
+        #[indexed] payment_token_type: &EgldOrEsdtTokenIdentifier,
+        #[indexed] payment_token_nonce: u64,
+        #[indexed] old_payment_amount: &BigUint,
+        #[indexed] new_payment_amount: &BigUint,
+        #[indexed] old_deadline: u64,
+        #[indexed] new_deadline: u64,
+        #[indexed] buyer: &ManagedAddress,
+    );
+
+    #[event("reject_offer_event")]
+    fn reject_offer_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] auction_id: u64,
+        #[indexed] offer_token_id: &TokenIdentifier,
+        #[indexed] offer_token_nonce: u64,
+        #[indexed] buyer: &ManagedAddress,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] reason: u32,
+    );
+
+    #[event("expired_offer_refund_event")]
+    fn expired_offer_refund_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] payment_token_type: &EgldOrEsdtTokenIdentifier,
+        #[indexed] payment_token_nonce: u64,
+        #[indexed] payment_amount: &BigUint,
+        #[indexed] offer_owner: &ManagedAddress,
+        #[indexed] bounty_amount: &BigUint,
+        #[indexed] caller: &ManagedAddress,
+    );
+
     #[event("accept_offer_token_event")]
     fn accept_offer_token_event(
         &self,
@@ -9,8 +9,12 @@ pub mod bidding;
 pub mod common_util_functions;
 pub mod events;
 pub mod offer;
+pub mod proxy_bid;
+pub mod sealed_bid;
+pub mod soft_close;
 pub mod token_distribution;
 pub mod token_whitelist;
+pub mod uniform_price;
 
 #[multiversx_sc::contract]
 pub trait EsdtNftMarketplace:
@@ -21,6 +25,10 @@ pub trait EsdtNftMarketplace:
     + token_whitelist::TokenWhitelistModule
     + events::EventsModule
     + common_util_functions::CommonUtilFunctions
+    + soft_close::SoftCloseModule
+    + sealed_bid::SealedBidModule
+    + proxy_bid::ProxyBidModule
+    + uniform_price::UniformPriceModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[init]
@@ -1,7 +1,13 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-#[derive(TopEncode, TopDecode, TypeAbi)]
+use crate::auction::{AuctionType, ListingCloseReason};
+
+pub const MAX_ATTRIBUTES_LEN: usize = 1_024;
+pub const MAX_TRAIT_LEN: usize = 128;
+pub const MAX_EXPIRED_OFFER_BOUNTY_PERCENTAGE: u64 = 500; // 5%
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone)]
 pub struct Offer<M: ManagedTypeApi> {
     pub offer_token: EsdtTokenPayment<M>,
     pub payment: EgldOrEsdtTokenPayment<M>,
@@ -10,6 +16,14 @@ pub struct Offer<M: ManagedTypeApi> {
     pub offer_owner: ManagedAddress<M>,
 }
 
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct CounterOffer<M: ManagedTypeApi> {
+    pub offer_token: EsdtTokenPayment<M>,
+    pub price: BigUint<M>,
+    pub deadline: u64,
+    pub seller: ManagedAddress<M>,
+}
+
 #[multiversx_sc::module]
 pub trait OfferModule:
     crate::auction::AuctionModule
@@ -17,6 +31,10 @@ pub trait OfferModule:
     + crate::token_whitelist::TokenWhitelistModule
     + crate::events::EventsModule
     + crate::common_util_functions::CommonUtilFunctions
+    + crate::soft_close::SoftCloseModule
+    + crate::sealed_bid::SealedBidModule
+    + crate::proxy_bid::ProxyBidModule
+    + crate::uniform_price::UniformPriceModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[payable("*")]
@@ -36,25 +54,58 @@ pub trait OfferModule:
         );
         require!(desired_amount > 0, "Amount must be greater than 0");
 
-        let payment = self.call_value().egld_or_single_esdt();
+        let opt_listing_id =
+            self.check_nft_in_marketplace(&desired_nft_id, desired_nft_nonce, opt_auction_id);
+
+        let offer_token = EsdtTokenPayment::new(desired_nft_id, desired_nft_nonce, desired_amount);
+        self.create_offer(offer_token, deadline, ManagedVec::new(), opt_listing_id)
+    }
+
+    // collection offers have a nonce of 0 and can be filled with any nonce of the collection
+    #[payable("*")]
+    #[endpoint(sendCollectionOffer)]
+    fn send_collection_offer(
+        &self,
+        desired_nft_id: TokenIdentifier,
+        desired_amount: BigUint,
+        deadline: u64,
+        required_traits: MultiValueEncoded<ManagedBuffer>,
+    ) -> u64 {
+        self.require_not_paused();
+        require!(desired_amount > 0, "Amount must be greater than 0");
+
+        let required_traits = required_traits.to_vec();
+        for required_trait in required_traits.iter() {
+            self.require_valid_trait(&required_trait);
+        }
+
+        let offer_token = EsdtTokenPayment::new(desired_nft_id, 0, desired_amount);
+        self.create_offer(offer_token, deadline, required_traits, None)
+    }
+
+    fn create_offer(
+        &self,
+        offer_token: EsdtTokenPayment,
+        deadline: u64,
+        required_traits: ManagedVec<ManagedBuffer>,
+        opt_listing_id: Option<u64>,
+    ) -> u64 {
+        let (payment, barter_tokens) = self.get_offer_payments();
         let caller = self.blockchain().get_caller();
         let current_time = self.blockchain().get_block_timestamp();
         require!(
-            payment.amount > 0u64,
+            payment.amount > 0u64 || !barter_tokens.is_empty(),
             "Payment amount must be greater than 0"
         );
         require!(deadline > current_time, "Deadline can't be in the past!");
 
-        self.require_token_whitelisted(&payment.token_identifier);
-        self.check_nft_in_marketplace(
-            &desired_nft_id,
-            desired_nft_nonce,
-            &desired_amount,
-            opt_auction_id,
-        );
-
-        let offer_token = EsdtTokenPayment::new(desired_nft_id, desired_nft_nonce, desired_amount);
+        if payment.amount > 0u64 {
+            self.require_token_whitelisted(&payment.token_identifier);
+        }
 
+        // offers for several units can be filled partially, at the same price for each unit
+        let price_per_unit = &payment.amount / &offer_token.amount;
+        self.require_valid_offer_price(&price_per_unit, !barter_tokens.is_empty());
         let offer = Offer {
             offer_token,
             payment,
@@ -62,31 +113,106 @@ pub trait OfferModule:
             deadline,
             offer_owner: caller,
         };
+        if let Some(auction_id) = opt_listing_id {
+            self.require_listing_min_offer(auction_id, &offer, &price_per_unit);
+        }
 
         let last_id_mapper = self.last_valid_offer_id();
         let offer_id = last_id_mapper.get() + 1;
         last_id_mapper.set(offer_id);
         self.offer_by_id(offer_id).set(&offer);
+        self.offer_price_per_unit(offer_id).set(&price_per_unit);
+        if let Some(auction_id) = opt_listing_id {
+            self.offer_listing_id(offer_id).set(auction_id);
+        }
 
+        if !required_traits.is_empty() {
+            self.offer_required_traits(offer_id).set(&required_traits);
+            self.emit_offer_traits_event(offer_id, &offer, &required_traits);
+        }
+        if !barter_tokens.is_empty() {
+            self.offer_barter_tokens(offer_id).set(&barter_tokens);
+            self.emit_offer_barter_tokens_event(offer_id, &offer, &barter_tokens);
+        }
         self.emit_offer_token_event(offer_id, offer);
 
         offer_id
     }
 
+    // NFTs and SFTs sent with an offer are bartered for the desired token, along with an optional
+    // payment in a fungible token, or in a whitelisted token with a nonce, like a meta ESDT
+    fn get_offer_payments(
+        &self,
+    ) -> (
+        EgldOrEsdtTokenPayment<Self::Api>,
+        ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) {
+        let egld_value = self.call_value().egld_value();
+        if egld_value > 0u64 {
+            return (
+                EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, egld_value),
+                ManagedVec::new(),
+            );
+        }
+
+        let mut payment =
+            EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, BigUint::zero());
+        let mut barter_tokens = ManagedVec::new();
+        for transfer in self.call_value().all_esdt_transfers().iter() {
+            if !self.is_offer_payment_token(&transfer) {
+                barter_tokens.push(transfer);
+                continue;
+            }
+
+            require!(
+                payment.amount == 0u64,
+                "Only one fungible token can be offered"
+            );
+            payment = EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::esdt(transfer.token_identifier),
+                transfer.token_nonce,
+                transfer.amount,
+            );
+        }
+
+        (payment, barter_tokens)
+    }
+
+    fn is_offer_payment_token(&self, transfer: &EsdtTokenPayment<Self::Api>) -> bool {
+        if transfer.token_nonce == 0 {
+            return true;
+        }
+
+        // any token can be used as payment while the whitelist is empty
+        let whitelisted_tokens = self.whitelisted_tokens();
+        whitelisted_tokens.is_empty()
+            || whitelisted_tokens.contains(&EgldOrEsdtTokenIdentifier::esdt(
+                transfer.token_identifier.clone(),
+            ))
+    }
+
+    fn require_valid_offer_price(&self, price_per_unit: &BigUint, is_barter_offer: bool) {
+        require!(
+            price_per_unit > &0u64 || is_barter_offer,
+            "Payment amount is too low for the desired amount"
+        );
+    }
+
+    // returns the id of the auction holding the desired token, if the marketplace has it
     fn check_nft_in_marketplace(
         &self,
         desired_nft_id: &TokenIdentifier,
         desired_nft_nonce: u64,
-        desired_amount: &BigUint,
         opt_auction_id: OptionalValue<u64>,
-    ) {
+    ) -> Option<u64> {
         let token_amount_in_marketplace = self.blockchain().get_sc_balance(
             &EgldOrEsdtTokenIdentifier::esdt(desired_nft_id.clone()),
             desired_nft_nonce,
         );
 
-        if &token_amount_in_marketplace < desired_amount {
-            return;
+        // the listing escrowing the token applies, whatever amount is asked for
+        if token_amount_in_marketplace == 0u64 {
+            return None;
         }
         require!(
             opt_auction_id.clone().into_option().is_some(),
@@ -95,13 +221,40 @@ pub trait OfferModule:
         let auction_id = unsafe { opt_auction_id.into_option().unwrap_unchecked() };
         let auction = self.try_get_auction(auction_id);
         require!(
-            &auction.auctioned_tokens.token_identifier == desired_nft_id,
+            &auction.auctioned_tokens.token_identifier == desired_nft_id
+                && auction.auctioned_tokens.token_nonce == desired_nft_nonce,
             "The auction does not contain the NFT"
         );
         require!(
-            auction.current_bid == BigUint::zero(),
+            auction.current_bid == BigUint::zero()
+                || auction.auction_type == AuctionType::SftOnePerPayment,
             "NFT auction has active bids"
         );
+
+        Some(auction_id)
+    }
+
+    fn require_listing_min_offer(
+        &self,
+        auction_id: u64,
+        offer: &Offer<Self::Api>,
+        price_per_unit: &BigUint,
+    ) {
+        let min_offer_mapper = self.listing_min_offer(auction_id);
+        if min_offer_mapper.is_empty() {
+            return;
+        }
+
+        let auction = self.try_get_auction(auction_id);
+        require!(
+            offer.payment.token_identifier == auction.payment_token
+                && offer.payment.token_nonce == auction.payment_nonce,
+            "Offer must be paid in the auction's payment token"
+        );
+        require!(
+            price_per_unit >= &min_offer_mapper.get(),
+            "Offer is below the minimum set by the seller"
+        );
     }
 
     #[endpoint(withdrawOffer)]
@@ -115,72 +268,674 @@ pub trait OfferModule:
             "Only the address that placed the offer can withdraw it!"
         );
 
-        self.send().direct(
-            &caller,
-            &offer.payment.token_identifier,
-            offer.payment.token_nonce,
-            &offer.payment.amount,
-        );
+        if offer.payment.amount > 0u64 {
+            self.send().direct(
+                &caller,
+                &offer.payment.token_identifier,
+                offer.payment.token_nonce,
+                &offer.payment.amount,
+            );
+        }
+        self.transfer_barter_tokens(offer_id, &caller);
 
-        self.offer_by_id(offer_id).clear();
+        self.return_counter_offer(offer_id, &caller);
+        self.clear_offer(offer_id);
         self.emit_withdraw_offer_event(offer_id, offer);
     }
 
+    // the payment can be raised by sending the difference, or lowered to get the difference back
+    #[payable("*")]
+    #[endpoint(updateOffer)]
+    fn update_offer(&self, offer_id: u64, new_payment_amount: BigUint, new_deadline: u64) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().egld_or_single_esdt();
+        let old_offer = self.try_get_offer(offer_id);
+        let current_time = self.blockchain().get_block_timestamp();
+
+        require!(
+            old_offer.offer_owner == caller,
+            "Only the address that placed the offer can update it!"
+        );
+        require!(current_time < old_offer.deadline, "Offer has expired");
+        require!(
+            new_deadline > current_time,
+            "Deadline can't be in the past!"
+        );
+
+        let new_price_per_unit = &new_payment_amount / &old_offer.offer_token.amount;
+        self.require_valid_offer_price(
+            &new_price_per_unit,
+            !self.offer_barter_tokens(offer_id).is_empty(),
+        );
+
+        if new_payment_amount > old_offer.payment.amount {
+            // barter-only offers hold an EGLD placeholder that was never checked
+            self.require_token_whitelisted(&old_offer.payment.token_identifier);
+            require!(
+                payment.token_identifier == old_offer.payment.token_identifier
+                    && payment.token_nonce == old_offer.payment.token_nonce,
+                "Wrong token used as payment"
+            );
+            require!(
+                payment.amount == &new_payment_amount - &old_offer.payment.amount,
+                "Wrong amount paid, must pay the difference to the new price"
+            );
+        } else {
+            require!(
+                payment.amount == 0u64,
+                "No payment needed when lowering the offer"
+            );
+            let refund_amount = &old_offer.payment.amount - &new_payment_amount;
+            if refund_amount > 0u64 {
+                self.send().direct(
+                    &caller,
+                    &old_offer.payment.token_identifier,
+                    old_offer.payment.token_nonce,
+                    &refund_amount,
+                );
+            }
+        }
+
+        let mut new_offer = old_offer.clone();
+        new_offer.payment.amount = new_payment_amount;
+        new_offer.deadline = new_deadline;
+        let listing_id = self.offer_listing_id(offer_id).get();
+        if listing_id != 0 && !self.auction_by_id(listing_id).is_empty() {
+            self.require_listing_min_offer(listing_id, &new_offer, &new_price_per_unit);
+        }
+
+        // a counter-offer was made against the previous terms
+        self.return_counter_offer(offer_id, &caller);
+        self.offer_by_id(offer_id).set(&new_offer);
+        self.offer_price_per_unit(offer_id).set(&new_price_per_unit);
+
+        self.emit_update_offer_event(offer_id, old_offer, new_offer);
+    }
+
+    // anyone can refund expired offers to their owners, for a small part of the escrowed payment
+    #[endpoint(cleanupExpiredOffers)]
+    fn cleanup_expired_offers(&self, offer_ids: MultiValueEncoded<u64>) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        let current_time = self.blockchain().get_block_timestamp();
+        let bounty_percentage = BigUint::from(self.expired_offer_bounty_percentage().get());
+
+        for offer_id in offer_ids {
+            let offer_mapper = self.offer_by_id(offer_id);
+            if offer_mapper.is_empty() {
+                continue;
+            }
+
+            let offer = offer_mapper.get();
+            if current_time < offer.deadline {
+                continue;
+            }
+
+            let bounty_amount = if offer.offer_owner == caller {
+                BigUint::zero()
+            } else {
+                self.calculate_cut_amount(&offer.payment.amount, &bounty_percentage)
+            };
+            let refund_amount = &offer.payment.amount - &bounty_amount;
+            self.transfer_or_save_payment(
+                &offer.offer_owner,
+                &offer.payment.token_identifier,
+                offer.payment.token_nonce,
+                &refund_amount,
+            );
+            self.transfer_or_save_payment(
+                &caller,
+                &offer.payment.token_identifier,
+                offer.payment.token_nonce,
+                &bounty_amount,
+            );
+            self.transfer_barter_tokens(offer_id, &offer.offer_owner);
+
+            self.return_counter_offer(offer_id, &caller);
+            self.clear_offer(offer_id);
+            self.emit_expired_offer_refund_event(offer_id, &offer, &bounty_amount, &caller);
+            self.emit_withdraw_offer_event(offer_id, offer);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(setBarterOfferFee)]
+    fn set_barter_offer_fee(&self, fee_token: TokenIdentifier, fee_amount: BigUint) {
+        require!(fee_token.is_valid_esdt_identifier(), "Invalid fee token");
+
+        if fee_amount == 0u64 {
+            self.barter_offer_fee().clear();
+        } else {
+            self.barter_offer_fee()
+                .set(EsdtTokenPayment::new(fee_token, 0, fee_amount));
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(setExpiredOfferBountyPercentage)]
+    fn set_expired_offer_bounty_percentage(&self, bounty_percentage: u64) {
+        require!(
+            bounty_percentage <= MAX_EXPIRED_OFFER_BOUNTY_PERCENTAGE,
+            "Bounty percentage exceeds the maximum"
+        );
+
+        self.expired_offer_bounty_percentage()
+            .set(bounty_percentage);
+    }
+
     #[payable("*")]
     #[endpoint(acceptOffer)]
     fn accept_offer(&self, offer_id: u64) {
         self.require_not_paused();
         let caller = self.blockchain().get_caller();
-        let offer_nft = self.call_value().single_esdt();
-        let offer = self.try_get_offer(offer_id);
+        let payments = self.call_value().all_esdt_transfers();
         require!(
-            offer_nft.amount == offer.offer_token.amount,
-            "The token amount is different from the offer"
+            !payments.is_empty() && payments.len() <= 2,
+            "Must send the offered token, and the barter offer fee if needed"
         );
+        let offer_nft = payments.get(0);
+        let mut offer = self.try_get_offer(offer_id);
         require!(
-            offer_nft.token_identifier == offer.offer_token.token_identifier,
-            "The sent token type is different from the offer"
+            offer_nft.amount <= offer.offer_token.amount,
+            "The token amount is higher than the offer"
         );
         require!(
-            offer_nft.token_nonce == offer.offer_token.token_nonce,
-            "The sent token nonce is different from the offer"
+            offer_nft.token_identifier == offer.offer_token.token_identifier,
+            "The sent token type is different from the offer"
         );
-        self.accept_offer_common(&caller, offer_id, offer);
+
+        let mut filled_offer = self.split_offer_fill(offer_id, &mut offer, &offer_nft.amount);
+        self.fill_offer_nonce(&mut filled_offer, offer_nft.token_nonce);
+        let opt_fee_payment = if payments.len() > 1 {
+            Some(payments.get(1))
+        } else {
+            None
+        };
+        self.take_barter_offer_fee(offer_id, &mut filled_offer, opt_fee_payment);
+        self.accept_offer_common(&caller, offer_id, filled_offer, offer);
     }
 
+    #[payable("*")]
     #[endpoint(withdrawAuctionAndAcceptOffer)]
     fn withdraw_auction_and_accept_offer(&self, auction_id: u64, offer_id: u64) {
         self.require_not_paused();
         let caller = self.blockchain().get_caller();
         let auction = self.try_get_auction(auction_id);
-        let offer = self.try_get_offer(offer_id);
+        let mut offer = self.try_get_offer(offer_id);
         require!(
             auction.auctioned_tokens.token_identifier == offer.offer_token.token_identifier,
             "The token id from the auction does not match the one from the offer"
         );
         require!(
-            auction.auctioned_tokens.amount == offer.offer_token.amount,
-            "The amount from the auction does not match the one from the offer"
+            auction.auctioned_tokens.amount <= offer.offer_token.amount,
+            "The amount from the auction is higher than the offer"
         );
+        self.require_listing_min_offer(
+            auction_id,
+            &offer,
+            &self.get_offer_price_per_unit(offer_id, &offer),
+        );
+        let mut filled_offer =
+            self.split_offer_fill(offer_id, &mut offer, &auction.auctioned_tokens.amount);
+        self.fill_offer_nonce(&mut filled_offer, auction.auctioned_tokens.token_nonce);
+        let opt_fee_payment = self.get_barter_offer_fee_payment();
+        self.take_barter_offer_fee(offer_id, &mut filled_offer, opt_fee_payment);
         require!(
-            auction.current_bid == BigUint::zero(),
+            auction.current_bid == BigUint::zero()
+                || auction.auction_type == AuctionType::SftOnePerPayment,
             "NFT auction has active bids"
         );
+        require!(
+            auction.auction_type != AuctionType::Bundle,
+            "Cannot accept offers for bundles"
+        );
 
         self.withdraw_auction_common(&caller, auction_id, auction);
-        self.accept_offer_common(&caller, offer_id, offer);
+        self.clear_auction(auction_id);
+        self.accept_offer_common(&caller, offer_id, filled_offer, offer);
+    }
+
+    // the owner of an auction can turn down offers for the auctioned token, which are refunded
+    #[endpoint(rejectOffer)]
+    fn reject_offer(&self, auction_id: u64, offer_id: u64, opt_reason: OptionalValue<u32>) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        let auction = self.try_get_auction(auction_id);
+        let offer = self.try_get_offer(offer_id);
+
+        require!(
+            auction.original_owner == caller,
+            "Only the original owner can reject offers"
+        );
+        require!(
+            auction.auctioned_tokens.token_identifier == offer.offer_token.token_identifier
+                && auction.auctioned_tokens.token_nonce == offer.offer_token.token_nonce,
+            "The offer is not for the auctioned token"
+        );
+
+        self.transfer_or_save_payment(
+            &offer.offer_owner,
+            &offer.payment.token_identifier,
+            offer.payment.token_nonce,
+            &offer.payment.amount,
+        );
+        self.transfer_barter_tokens(offer_id, &offer.offer_owner);
+
+        self.return_counter_offer(offer_id, &caller);
+        self.clear_offer(offer_id);
+
+        let reason = opt_reason.into_option().unwrap_or_default();
+        self.emit_reject_offer_event(offer_id, auction_id, &offer, &caller, reason);
+        self.emit_withdraw_offer_event(offer_id, offer);
+    }
+
+    // fills the offer from the tokens of an SFT listing, which stays open with the remaining ones
+    #[payable("*")]
+    #[endpoint(acceptOfferOnListing)]
+    fn accept_offer_on_listing(&self, auction_id: u64, offer_id: u64) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        let mut auction = self.try_get_auction(auction_id);
+        let mut offer = self.try_get_offer(offer_id);
+        require!(
+            auction.original_owner == caller,
+            "Only the original owner can accept offers on the listing"
+        );
+        require!(
+            auction.auction_type == AuctionType::SftOnePerPayment,
+            "Offers can only be accepted on SFT listings"
+        );
+        require!(
+            auction.auctioned_tokens.token_identifier == offer.offer_token.token_identifier,
+            "The token id from the auction does not match the one from the offer"
+        );
+        // offers that were not placed for the listing must also meet its minimum
+        self.require_listing_min_offer(
+            auction_id,
+            &offer,
+            &self.get_offer_price_per_unit(offer_id, &offer),
+        );
+
+        let fill_amount = if offer.offer_token.amount < auction.auctioned_tokens.amount {
+            offer.offer_token.amount.clone()
+        } else {
+            auction.auctioned_tokens.amount.clone()
+        };
+        let mut filled_offer = self.split_offer_fill(offer_id, &mut offer, &fill_amount);
+        self.fill_offer_nonce(&mut filled_offer, auction.auctioned_tokens.token_nonce);
+        let opt_fee_payment = self.get_barter_offer_fee_payment();
+        self.take_barter_offer_fee(offer_id, &mut filled_offer, opt_fee_payment);
+
+        auction.auctioned_tokens.amount -= &fill_amount;
+        if auction.auctioned_tokens.amount == 0 {
+            self.clear_auction(auction_id);
+            self.emit_close_listing_event(auction_id, auction.clone(), ListingCloseReason::SoldOut);
+        } else {
+            self.auction_by_id(auction_id).set(&auction);
+        }
+
+        self.emit_accept_offer_on_listing_event(auction_id, offer_id, &auction, &fill_amount);
+        self.accept_offer_common(&caller, offer_id, filled_offer, offer);
     }
 
-    fn accept_offer_common(&self, seller: &ManagedAddress, offer_id: u64, offer: Offer<Self::Api>) {
+    #[payable("*")]
+    #[endpoint(counterOffer)]
+    fn send_counter_offer(&self, offer_id: u64, price: BigUint, deadline: u64) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        let offer_nft = self.call_value().single_esdt();
+        let mut offer = self.try_get_offer(offer_id);
         let current_time = self.blockchain().get_block_timestamp();
+
         require!(current_time < offer.deadline, "Offer has expired");
-        require!(&offer.offer_owner != seller, "Cannot accept your own offer");
+        require!(offer.offer_owner != caller, "Cannot counter your own offer");
+        require!(
+            offer_nft.amount == offer.offer_token.amount,
+            "The token amount is different from the offer"
+        );
+        require!(
+            offer_nft.token_identifier == offer.offer_token.token_identifier,
+            "The sent token type is different from the offer"
+        );
+        self.fill_offer_nonce(&mut offer, offer_nft.token_nonce);
+        self.require_offer_traits_match(offer_id, &offer);
+
+        require!(
+            price > offer.payment.amount,
+            "Counter price must be higher than the offer"
+        );
+        require!(
+            deadline > current_time && deadline <= offer.deadline,
+            "Invalid counter-offer deadline"
+        );
+
+        // an expired counter-offer can be replaced by a new one
+        let counter_offer_mapper = self.counter_offer(offer_id);
+        if !counter_offer_mapper.is_empty() {
+            require!(
+                current_time >= counter_offer_mapper.get().deadline,
+                "Offer already has an active counter-offer"
+            );
+            self.return_counter_offer(offer_id, &caller);
+        }
+
+        let counter_offer = CounterOffer {
+            offer_token: offer.offer_token.clone(),
+            price,
+            deadline,
+            seller: caller,
+        };
+        self.counter_offer(offer_id).set(&counter_offer);
+
+        self.emit_counter_offer_event(offer_id, &offer, &counter_offer);
+    }
+
+    #[payable("*")]
+    #[endpoint(acceptCounterOffer)]
+    fn accept_counter_offer(&self, offer_id: u64) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().egld_or_single_esdt();
+        let mut offer = self.try_get_offer(offer_id);
+        let counter_offer = self.try_get_counter_offer(offer_id);
+        let current_time = self.blockchain().get_block_timestamp();
+
+        require!(
+            offer.offer_owner == caller,
+            "Only the address that placed the offer can accept the counter-offer"
+        );
+        require!(
+            current_time < counter_offer.deadline,
+            "Counter-offer has expired"
+        );
+        self.require_token_whitelisted(&offer.payment.token_identifier);
+        require!(
+            payment.token_identifier == offer.payment.token_identifier
+                && payment.token_nonce == offer.payment.token_nonce,
+            "Wrong token used as payment"
+        );
+        require!(
+            payment.amount == &counter_offer.price - &offer.payment.amount,
+            "Wrong amount paid, must pay the difference to the counter price"
+        );
+
+        // the offer is completed at the counter price, with the escrowed token
+        let mut filled_offer =
+            self.split_offer_fill(offer_id, &mut offer, &counter_offer.offer_token.amount);
+        filled_offer.payment.amount = counter_offer.price;
+        filled_offer.offer_token = counter_offer.offer_token;
+        offer.payment.amount = BigUint::zero();
+        self.counter_offer(offer_id).clear();
+
+        self.accept_offer_common(&counter_offer.seller, offer_id, filled_offer, offer);
+    }
+
+    #[endpoint(withdrawCounterOffer)]
+    fn withdraw_counter_offer(&self, offer_id: u64) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        let offer = self.try_get_offer(offer_id);
+        let counter_offer = self.try_get_counter_offer(offer_id);
+
+        require!(
+            counter_offer.seller == caller || offer.offer_owner == caller,
+            "Only the seller or the offer owner can withdraw the counter-offer"
+        );
+
+        self.return_counter_offer(offer_id, &caller);
+    }
+
+    fn transfer_barter_tokens(&self, offer_id: u64, to: &ManagedAddress) {
+        for barter_token in self.offer_barter_tokens(offer_id).take().iter() {
+            self.transfer_or_save_payment(
+                to,
+                &EgldOrEsdtTokenIdentifier::esdt(barter_token.token_identifier),
+                barter_token.token_nonce,
+                &barter_token.amount,
+            );
+        }
+    }
+
+    // the listed token is already in the marketplace, so only the barter offer fee can be sent
+    fn get_barter_offer_fee_payment(&self) -> Option<EsdtTokenPayment<Self::Api>> {
+        require!(
+            self.call_value().egld_value() == 0u64,
+            "Only the barter offer fee can be paid"
+        );
+        let payments = self.call_value().all_esdt_transfers();
+        require!(payments.len() <= 1, "Only the barter offer fee can be paid");
+
+        if payments.is_empty() {
+            None
+        } else {
+            Some(payments.get(0))
+        }
+    }
+
+    // swaps without a fungible payment are valued at a flat fee instead, which the seller sends along
+    // with the token. The royalties and the marketplace cut are charged on it, and the rest is returned.
+    fn take_barter_offer_fee(
+        &self,
+        offer_id: u64,
+        filled_offer: &mut Offer<Self::Api>,
+        opt_fee_payment: Option<EsdtTokenPayment<Self::Api>>,
+    ) {
+        let fee_mapper = self.barter_offer_fee();
+        let is_fee_charged = !self.offer_barter_tokens(offer_id).is_empty()
+            && filled_offer.payment.amount == 0u64
+            && !fee_mapper.is_empty();
+        if !is_fee_charged {
+            require!(
+                opt_fee_payment.is_none(),
+                "No fee is charged for this offer"
+            );
+            return;
+        }
+
+        let fee = fee_mapper.get();
+        let fee_payment = match opt_fee_payment {
+            Some(fee_payment) => fee_payment,
+            None => sc_panic!("Must pay the barter offer fee"),
+        };
+        require!(
+            fee_payment.token_identifier == fee.token_identifier
+                && fee_payment.token_nonce == 0
+                && fee_payment.amount == fee.amount,
+            "Wrong barter offer fee paid"
+        );
+
+        filled_offer.payment = EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(fee.token_identifier),
+            0,
+            fee.amount,
+        );
+    }
+
+    // sends the escrowed token back to the seller of the counter-offer, if any
+    fn return_counter_offer(&self, offer_id: u64, caller: &ManagedAddress) {
+        let counter_offer_mapper = self.counter_offer(offer_id);
+        if counter_offer_mapper.is_empty() {
+            return;
+        }
+
+        let counter_offer = counter_offer_mapper.get();
+        counter_offer_mapper.clear();
+        self.transfer_or_save_payment(
+            &counter_offer.seller,
+            &EgldOrEsdtTokenIdentifier::esdt(counter_offer.offer_token.token_identifier.clone()),
+            counter_offer.offer_token.token_nonce,
+            &counter_offer.offer_token.amount,
+        );
+
+        self.emit_withdraw_counter_offer_event(offer_id, &counter_offer, caller);
+    }
+
+    fn fill_offer_nonce(&self, offer: &mut Offer<Self::Api>, nft_nonce: u64) {
+        if offer.offer_token.token_nonce == 0 {
+            require!(nft_nonce > 0, "Can't accept offers with fungible tokens");
+            offer.offer_token.token_nonce = nft_nonce;
+        } else {
+            require!(
+                offer.offer_token.token_nonce == nft_nonce,
+                "The sent token nonce is different from the offer"
+            );
+        }
+    }
+
+    // moves `amount` units out of the offer, paid at the offer's price per unit
+    fn split_offer_fill(
+        &self,
+        offer_id: u64,
+        offer: &mut Offer<Self::Api>,
+        amount: &BigUint,
+    ) -> Offer<Self::Api> {
+        require!(amount > &0u64, "Amount must be greater than 0");
+        let is_barter_offer = !self.offer_barter_tokens(offer_id).is_empty();
+        require!(
+            !is_barter_offer || amount == &offer.offer_token.amount,
+            "Barter offers can't be filled partially"
+        );
+
+        let mut filled_offer = offer.clone();
+        filled_offer.offer_token.amount = amount.clone();
+        // barter offers are filled entirely, along with their whole payment
+        if !is_barter_offer {
+            filled_offer.payment.amount = &self.get_offer_price_per_unit(offer_id, offer) * amount;
+        }
+
+        offer.offer_token.amount -= amount;
+        offer.payment.amount -= &filled_offer.payment.amount;
+
+        filled_offer
+    }
+
+    // offers placed before partial fills were supported don't have a price per unit stored
+    fn get_offer_price_per_unit(&self, offer_id: u64, offer: &Offer<Self::Api>) -> BigUint {
+        let price_per_unit_mapper = self.offer_price_per_unit(offer_id);
+        if !price_per_unit_mapper.is_empty() {
+            return price_per_unit_mapper.get();
+        }
+
+        &offer.payment.amount / &offer.offer_token.amount
+    }
+
+    fn accept_offer_common(
+        &self,
+        seller: &ManagedAddress,
+        offer_id: u64,
+        filled_offer: Offer<Self::Api>,
+        remaining_offer: Offer<Self::Api>,
+    ) {
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(current_time < filled_offer.deadline, "Offer has expired");
+        require!(
+            &filled_offer.offer_owner != seller,
+            "Cannot accept your own offer"
+        );
+        self.require_offer_traits_match(offer_id, &filled_offer);
+        self.return_counter_offer(offer_id, seller);
 
         let marketplace_cut_percentage = self.bid_cut_percentage().get();
-        self.distribute_tokens_after_offer_accept(&offer, seller, &marketplace_cut_percentage);
-        self.offer_by_id(offer_id).clear();
+        self.distribute_tokens_after_offer_accept(
+            &filled_offer,
+            seller,
+            &marketplace_cut_percentage,
+        );
+        self.transfer_barter_tokens(offer_id, seller);
 
-        self.emit_accept_offer_event(offer_id, offer, seller);
+        if remaining_offer.offer_token.amount > 0 {
+            self.offer_by_id(offer_id).set(&remaining_offer);
+        } else {
+            // refund what's left after paying each unit at the same price
+            self.transfer_or_save_payment(
+                &remaining_offer.offer_owner,
+                &remaining_offer.payment.token_identifier,
+                remaining_offer.payment.token_nonce,
+                &remaining_offer.payment.amount,
+            );
+            self.clear_offer(offer_id);
+        }
+
+        self.emit_accept_offer_event(offer_id, filled_offer, seller);
+    }
+
+    fn require_offer_traits_match(&self, offer_id: u64, offer: &Offer<Self::Api>) {
+        let required_traits_mapper = self.offer_required_traits(offer_id);
+        if required_traits_mapper.is_empty() {
+            return;
+        }
+
+        let nft_info = self.get_nft_info(
+            &offer.offer_token.token_identifier,
+            offer.offer_token.token_nonce,
+        );
+        require!(
+            self.attributes_match_traits(&nft_info.attributes, &required_traits_mapper.get()),
+            "Token attributes do not match the offer"
+        );
+    }
+
+    // traits are "key:value" pairs, without any ';' or ',' separators
+    fn require_valid_trait(&self, required_trait: &ManagedBuffer) {
+        require!(required_trait.len() <= MAX_TRAIT_LEN, "Trait is too long");
+
+        let mut trait_buffer = [0u8; MAX_TRAIT_LEN];
+        let trait_bytes = required_trait.load_to_byte_array(&mut trait_buffer);
+        let is_separator = |b: &u8| *b == b':' || *b == b';' || *b == b',';
+        let is_valid = match trait_bytes.iter().position(|b| *b == b':') {
+            Some(pos) => {
+                pos > 0
+                    && pos + 1 < trait_bytes.len()
+                    && !trait_bytes[..pos].iter().any(is_separator)
+                    && !trait_bytes[pos + 1..].iter().any(is_separator)
+            }
+            None => false,
+        };
+        require!(is_valid, "Invalid trait format, expected key:value");
+    }
+
+    // attributes are ';' separated "key:value" entries, where the value can be a ',' separated
+    // list, e.g. "metadata:<cid>/1.json;tags:gold,rare". Malformed entries never match.
+    fn attributes_match_traits(
+        &self,
+        attributes: &ManagedBuffer,
+        required_traits: &ManagedVec<ManagedBuffer>,
+    ) -> bool {
+        if attributes.len() > MAX_ATTRIBUTES_LEN {
+            return false;
+        }
+
+        let mut attributes_buffer = [0u8; MAX_ATTRIBUTES_LEN];
+        let attributes_bytes = attributes.load_to_byte_array(&mut attributes_buffer);
+        let mut trait_buffer = [0u8; MAX_TRAIT_LEN];
+        for required_trait in required_traits.iter() {
+            let trait_bytes = required_trait.load_to_byte_array(&mut trait_buffer);
+            if !self.attributes_contain_trait(attributes_bytes, trait_bytes) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn attributes_contain_trait(&self, attributes: &[u8], required_trait: &[u8]) -> bool {
+        let (trait_key, trait_value) = match required_trait.iter().position(|b| *b == b':') {
+            Some(pos) => (&required_trait[..pos], &required_trait[pos + 1..]),
+            None => return false,
+        };
+
+        attributes
+            .split(|b| *b == b';')
+            .any(|entry| match entry.iter().position(|b| *b == b':') {
+                Some(pos) => {
+                    &entry[..pos] == trait_key
+                        && entry[pos + 1..]
+                            .split(|b| *b == b',')
+                            .any(|value| value == trait_value)
+                }
+                None => false,
+            })
     }
 
     fn get_transfer_data(&self, address: &ManagedAddress, data: &'static [u8]) -> &[u8] {
@@ -198,10 +953,57 @@ pub trait OfferModule:
         offer_mapper.get()
     }
 
+    fn clear_offer(&self, offer_id: u64) {
+        self.offer_by_id(offer_id).clear();
+        self.offer_required_traits(offer_id).clear();
+        self.offer_price_per_unit(offer_id).clear();
+        self.offer_barter_tokens(offer_id).clear();
+        self.offer_listing_id(offer_id).clear();
+    }
+
+    #[view(getCounterOffer)]
+    fn try_get_counter_offer(&self, offer_id: u64) -> CounterOffer<Self::Api> {
+        let counter_offer_mapper = self.counter_offer(offer_id);
+        require!(
+            !counter_offer_mapper.is_empty(),
+            "Counter-offer does not exist"
+        );
+        counter_offer_mapper.get()
+    }
+
     #[view(getLastValidOfferId)]
     #[storage_mapper("lastValidOfferId")]
     fn last_valid_offer_id(&self) -> SingleValueMapper<u64>;
 
     #[storage_mapper("offerById")]
     fn offer_by_id(&self, offer_id: u64) -> SingleValueMapper<Offer<Self::Api>>;
+
+    #[view(getOfferRequiredTraits)]
+    #[storage_mapper("offerRequiredTraits")]
+    fn offer_required_traits(&self, offer_id: u64) -> SingleValueMapper<ManagedVec<ManagedBuffer>>;
+
+    #[view(getOfferPricePerUnit)]
+    #[storage_mapper("offerPricePerUnit")]
+    fn offer_price_per_unit(&self, offer_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[view(getOfferBarterTokens)]
+    #[storage_mapper("offerBarterTokens")]
+    fn offer_barter_tokens(&self, offer_id: u64)
+        -> SingleValueMapper<ManagedVec<EsdtTokenPayment>>;
+
+    // 0 for offers that were not placed on a listing
+    #[view(getOfferListingId)]
+    #[storage_mapper("offerListingId")]
+    fn offer_listing_id(&self, offer_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getExpiredOfferBountyPercentage)]
+    #[storage_mapper("expiredOfferBountyPercentage")]
+    fn expired_offer_bounty_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getBarterOfferFee)]
+    #[storage_mapper("barterOfferFee")]
+    fn barter_offer_fee(&self) -> SingleValueMapper<EsdtTokenPayment<Self::Api>>;
+
+    #[storage_mapper("counterOffer")]
+    fn counter_offer(&self, offer_id: u64) -> SingleValueMapper<CounterOffer<Self::Api>>;
 }
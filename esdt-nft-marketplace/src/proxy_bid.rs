@@ -0,0 +1,39 @@
+multiversx_sc::imports!();
+
+use crate::auction::Auction;
+
+#[multiversx_sc::module]
+pub trait ProxyBidModule:
+    crate::token_distribution::TokenDistributionModule
+    + crate::common_util_functions::CommonUtilFunctions
+    + multiversx_sc_modules::pause::PauseModule
+{
+    // the amount escrowed by the current winner, which is their maximum for proxy bids
+    fn get_winner_max_bid(&self, auction_id: u64, auction: &Auction<Self::Api>) -> BigUint {
+        let proxy_max_bid_mapper = self.proxy_max_bid(auction_id);
+        if proxy_max_bid_mapper.is_empty() {
+            auction.current_bid.clone()
+        } else {
+            proxy_max_bid_mapper.get()
+        }
+    }
+
+    fn refund_unused_proxy_bid(&self, auction_id: u64, auction: &Auction<Self::Api>) {
+        let proxy_max_bid_mapper = self.proxy_max_bid(auction_id);
+        if proxy_max_bid_mapper.is_empty() {
+            return;
+        }
+
+        let unused_amount = proxy_max_bid_mapper.get() - &auction.current_bid;
+        self.transfer_or_save_payment(
+            &auction.current_winner,
+            &auction.payment_token,
+            auction.payment_nonce,
+            &unused_amount,
+        );
+        proxy_max_bid_mapper.clear();
+    }
+
+    #[storage_mapper("proxyMaxBid")]
+    fn proxy_max_bid(&self, auction_id: u64) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,87 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::auction::Auction;
+
+// bounds the gas needed to settle the unrevealed bids of an auction
+pub const MAX_SEALED_BIDS: usize = 100;
+
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct SealedBid<M: ManagedTypeApi> {
+    pub commitment: ManagedByteArray<M, 32>,
+    pub deposit: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait SealedBidModule:
+    crate::token_distribution::TokenDistributionModule
+    + crate::common_util_functions::CommonUtilFunctions
+    + multiversx_sc_modules::pause::PauseModule
+{
+    #[only_owner]
+    #[endpoint(setSlashUnrevealedBids)]
+    fn set_slash_unrevealed_bids(&self, slash_unrevealed_bids: bool) {
+        self.slash_unrevealed_bids().set(slash_unrevealed_bids);
+    }
+
+    // the commitment is bound to the auction and the bidder, so it can't be copied by others.
+    // The amount is length-prefixed, so no other amount and salt can produce the same data.
+    fn compute_bid_commitment(
+        &self,
+        auction_id: u64,
+        bidder: &ManagedAddress,
+        bid_amount: &BigUint,
+        salt: &ManagedBuffer,
+    ) -> ManagedByteArray<Self::Api, 32> {
+        let bid_amount_bytes = bid_amount.to_bytes_be_buffer();
+
+        let mut commitment_data = ManagedBuffer::new();
+        commitment_data.append_bytes(&auction_id.to_be_bytes());
+        commitment_data.append(bidder.as_managed_buffer());
+        commitment_data.append_bytes(&(bid_amount_bytes.len() as u32).to_be_bytes());
+        commitment_data.append(&bid_amount_bytes);
+        commitment_data.append(salt);
+
+        self.crypto().keccak256(&commitment_data)
+    }
+
+    fn refund_or_slash_sealed_bid(
+        &self,
+        auction: &Auction<Self::Api>,
+        bidder: &ManagedAddress,
+        deposit: &BigUint,
+    ) {
+        let receiver = if self.slash_unrevealed_bids().get() {
+            &auction.original_owner
+        } else {
+            bidder
+        };
+
+        self.transfer_or_save_payment(
+            receiver,
+            &auction.payment_token,
+            auction.payment_nonce,
+            deposit,
+        );
+    }
+
+    fn settle_unrevealed_sealed_bids(&self, auction_id: u64, auction: &Auction<Self::Api>) {
+        let mut sealed_bids_mapper = self.sealed_bids(auction_id);
+        for (bidder, sealed_bid) in sealed_bids_mapper.iter() {
+            self.refund_or_slash_sealed_bid(auction, &bidder, &sealed_bid.deposit);
+        }
+
+        sealed_bids_mapper.clear();
+    }
+
+    #[view(getSlashUnrevealedBids)]
+    #[storage_mapper("slashUnrevealedBids")]
+    fn slash_unrevealed_bids(&self) -> SingleValueMapper<bool>;
+
+    #[view(getAuctionRevealDeadline)]
+    #[storage_mapper("revealDeadline")]
+    fn reveal_deadline(&self, auction_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("sealedBids")]
+    fn sealed_bids(&self, auction_id: u64) -> MapMapper<ManagedAddress, SealedBid<Self::Api>>;
+}
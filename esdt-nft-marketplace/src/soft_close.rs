@@ -0,0 +1,82 @@
+multiversx_sc::imports!();
+
+use crate::auction::Auction;
+
+#[multiversx_sc::module]
+pub trait SoftCloseModule {
+    #[only_owner]
+    #[endpoint(setDefaultSoftClose)]
+    fn set_default_soft_close(&self, window: u64, extension: u64) {
+        self.require_valid_soft_close(window, extension);
+
+        self.default_soft_close_window().set(window);
+        self.default_soft_close_extension().set(extension);
+    }
+
+    #[only_owner]
+    #[endpoint(setMaxSoftClose)]
+    fn set_max_soft_close(&self, max_window: u64, max_extension: u64) {
+        require!(
+            self.default_soft_close_window().get() <= max_window,
+            "Default soft close window exceeds the maximum"
+        );
+        require!(
+            self.default_soft_close_extension().get() <= max_extension,
+            "Default soft close extension exceeds the maximum"
+        );
+
+        self.max_soft_close_window().set(max_window);
+        self.max_soft_close_extension().set(max_extension);
+    }
+
+    fn require_valid_soft_close(&self, window: u64, extension: u64) {
+        require!(
+            window <= self.max_soft_close_window().get(),
+            "Soft close window exceeds the maximum"
+        );
+        require!(
+            extension <= self.max_soft_close_extension().get(),
+            "Soft close extension exceeds the maximum"
+        );
+    }
+
+    fn try_extend_deadline(&self, auction_id: u64, auction: &mut Auction<Self::Api>) -> bool {
+        let soft_close_extension = self.soft_close_extension(auction_id).get();
+        if soft_close_extension == 0 {
+            return false;
+        }
+
+        let current_time = self.blockchain().get_block_timestamp();
+        if auction.deadline - current_time > self.soft_close_window(auction_id).get() {
+            return false;
+        }
+
+        auction.deadline += soft_close_extension;
+
+        true
+    }
+
+    #[view(getDefaultSoftCloseWindow)]
+    #[storage_mapper("defaultSoftCloseWindow")]
+    fn default_soft_close_window(&self) -> SingleValueMapper<u64>;
+
+    #[view(getDefaultSoftCloseExtension)]
+    #[storage_mapper("defaultSoftCloseExtension")]
+    fn default_soft_close_extension(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMaxSoftCloseWindow)]
+    #[storage_mapper("maxSoftCloseWindow")]
+    fn max_soft_close_window(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMaxSoftCloseExtension)]
+    #[storage_mapper("maxSoftCloseExtension")]
+    fn max_soft_close_extension(&self) -> SingleValueMapper<u64>;
+
+    #[view(getAuctionSoftCloseWindow)]
+    #[storage_mapper("softCloseWindow")]
+    fn soft_close_window(&self, auction_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getAuctionSoftCloseExtension)]
+    #[storage_mapper("softCloseExtension")]
+    fn soft_close_extension(&self, auction_id: u64) -> SingleValueMapper<u64>;
+}
@@ -15,6 +15,18 @@ pub struct BidSplitAmounts<M: ManagedTypeApi> {
 pub trait TokenDistributionModule:
     crate::common_util_functions::CommonUtilFunctions + multiversx_sc_modules::pause::PauseModule
 {
+    #[only_owner]
+    #[endpoint(setKeeperRewardPercentage)]
+    fn set_keeper_reward_percentage(&self, keeper_reward_percentage: u64) {
+        require!(
+            keeper_reward_percentage <= PERCENTAGE_TOTAL,
+            "Invalid percentage value, should be between 0 and 10,000"
+        );
+
+        self.keeper_reward_percentage()
+            .set(keeper_reward_percentage);
+    }
+
     #[endpoint(claimTokens)]
     fn claim_tokens(
         &self,
@@ -107,15 +119,25 @@ pub trait TokenDistributionModule:
 
     fn distribute_tokens_after_auction_end(
         &self,
+        auction_id: u64,
         auction: &Auction<Self::Api>,
         opt_sft_amount: Option<&BigUint>,
+        opt_keeper: Option<&ManagedAddress>,
     ) {
         let nft_type = &auction.auctioned_tokens.token_identifier;
         let nft_nonce = auction.auctioned_tokens.token_nonce;
 
-        if !auction.current_winner.is_zero() {
+        if auction.auction_type == AuctionType::Bundle && !auction.current_winner.is_zero() {
+            self.distribute_bundle_after_auction_end(auction_id, auction, opt_keeper);
+        } else if !auction.current_winner.is_zero() {
             let nft_info = self.get_nft_info(nft_type, nft_nonce);
-            let bid_split_amounts = self.calculate_winning_bid_split(auction);
+            let mut bid_split_amounts = self.calculate_winning_bid_split(auction);
+            self.pay_keeper_reward(
+                opt_keeper,
+                &auction.payment_token,
+                auction.payment_nonce,
+                &mut bid_split_amounts.marketplace,
+            );
 
             // send NFT to auction winner
             let nft_amount = BigUint::from(NFT_AMOUNT);
@@ -141,12 +163,117 @@ pub trait TokenDistributionModule:
             );
         } else {
             // return to original owner
+            self.transfer_auctioned_tokens(&auction.original_owner, auction_id, auction);
+        }
+    }
+
+    fn distribute_bundle_after_auction_end(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        opt_keeper: Option<&ManagedAddress>,
+    ) {
+        let bundled_tokens = self.bundled_tokens(auction_id).get();
+        let bundle_weights = self.bundle_weights(auction_id).get();
+        let bundle_royalties = self.bundle_royalties(auction_id).get();
+        let total_weight: u64 = bundle_weights.iter().sum();
+
+        // send part as cut for contract owner
+        let mut marketplace_amount =
+            self.calculate_cut_amount(&auction.current_bid, &auction.marketplace_cut_percentage);
+        let mut seller_amount = &auction.current_bid - &marketplace_amount;
+        self.pay_keeper_reward(
+            opt_keeper,
+            &auction.payment_token,
+            auction.payment_nonce,
+            &mut marketplace_amount,
+        );
+        let sc_owner = self.blockchain().get_owner_address();
+        self.transfer_or_save_payment(
+            &sc_owner,
+            &auction.payment_token,
+            auction.payment_nonce,
+            &marketplace_amount,
+        );
+
+        // send royalties to the creator of each item, based on the item's share of the price and
+        // on the royalties it had when the bundle was listed
+        for (index, item) in bundled_tokens.iter().enumerate() {
+            let nft_info = self.get_nft_info(&item.token_identifier, item.token_nonce);
+            let item_price = &auction.current_bid * bundle_weights.get(index) / total_weight;
+            let item_royalties = bundle_royalties.get(index);
+            let creator_royalties = self.calculate_cut_amount(&item_price, &item_royalties);
+            seller_amount -= &creator_royalties;
+
             self.transfer_or_save_payment(
-                &auction.original_owner,
-                &EgldOrEsdtTokenIdentifier::esdt(nft_type.clone()),
-                nft_nonce,
+                &nft_info.creator,
+                &auction.payment_token,
+                auction.payment_nonce,
+                &creator_royalties,
+            );
+        }
+
+        // send rest of the bid to original owner
+        self.transfer_or_save_payment(
+            &auction.original_owner,
+            &auction.payment_token,
+            auction.payment_nonce,
+            &seller_amount,
+        );
+
+        // send bundled tokens to new owner
+        self.transfer_auctioned_tokens(&auction.current_winner, auction_id, auction);
+    }
+
+    // whoever settles the auction gets part of the marketplace cut
+    fn pay_keeper_reward(
+        &self,
+        opt_keeper: Option<&ManagedAddress>,
+        payment_token_id: &EgldOrEsdtTokenIdentifier,
+        payment_token_nonce: u64,
+        marketplace_amount: &mut BigUint,
+    ) {
+        let keeper = match opt_keeper {
+            Some(keeper) => keeper,
+            None => return,
+        };
+
+        let keeper_reward_percentage = BigUint::from(self.keeper_reward_percentage().get());
+        let keeper_reward =
+            self.calculate_cut_amount(marketplace_amount, &keeper_reward_percentage);
+        *marketplace_amount -= &keeper_reward;
+
+        self.transfer_or_save_payment(
+            keeper,
+            payment_token_id,
+            payment_token_nonce,
+            &keeper_reward,
+        );
+    }
+
+    fn transfer_auctioned_tokens(
+        &self,
+        to: &ManagedAddress,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+    ) {
+        if auction.auction_type != AuctionType::Bundle {
+            self.transfer_or_save_payment(
+                to,
+                &EgldOrEsdtTokenIdentifier::esdt(auction.auctioned_tokens.token_identifier.clone()),
+                auction.auctioned_tokens.token_nonce,
                 &auction.auctioned_tokens.amount,
             );
+            return;
+        }
+
+        for item in self.bundled_tokens(auction_id).get().iter() {
+            self.transfer_or_save_payment(
+                to,
+                &EgldOrEsdtTokenIdentifier::esdt(item.token_identifier),
+                item.token_nonce,
+                &item.amount,
+            );
         }
     }
 
@@ -189,6 +316,26 @@ pub trait TokenDistributionModule:
         original_owner: &ManagedAddress,
         new_owner: &ManagedAddress,
         bid_split_amounts: &BidSplitAmounts<Self::Api>,
+    ) {
+        self.distribute_sale_payments(
+            payment_token_id,
+            payment_token_nonce,
+            creator,
+            original_owner,
+            bid_split_amounts,
+        );
+
+        // send NFT to new owner
+        self.transfer_or_save_payment(new_owner, nft_type, nft_nonce, nft_amount_to_send);
+    }
+
+    fn distribute_sale_payments(
+        &self,
+        payment_token_id: &EgldOrEsdtTokenIdentifier,
+        payment_token_nonce: u64,
+        creator: &ManagedAddress,
+        original_owner: &ManagedAddress,
+        bid_split_amounts: &BidSplitAmounts<Self::Api>,
     ) {
         // send part as cut for contract owner
         let sc_owner = self.blockchain().get_owner_address();
@@ -214,9 +361,6 @@ pub trait TokenDistributionModule:
             payment_token_nonce,
             &bid_split_amounts.seller,
         );
-
-        // send NFT to new owner
-        self.transfer_or_save_payment(new_owner, nft_type, nft_nonce, nft_amount_to_send);
     }
 
     fn transfer_or_save_payment(
@@ -238,6 +382,25 @@ pub trait TokenDistributionModule:
         }
     }
 
+    #[view(getAuctionBundledTokens)]
+    #[storage_mapper("bundledTokens")]
+    fn bundled_tokens(
+        &self,
+        auction_id: u64,
+    ) -> SingleValueMapper<ManagedVec<EsdtTokenPayment<Self::Api>>>;
+
+    #[view(getAuctionBundleWeights)]
+    #[storage_mapper("bundleWeights")]
+    fn bundle_weights(&self, auction_id: u64) -> SingleValueMapper<ManagedVec<u64>>;
+
+    #[view(getAuctionBundleRoyalties)]
+    #[storage_mapper("bundleRoyalties")]
+    fn bundle_royalties(&self, auction_id: u64) -> SingleValueMapper<ManagedVec<BigUint>>;
+
+    #[view(getKeeperRewardPercentage)]
+    #[storage_mapper("keeperRewardPercentage")]
+    fn keeper_reward_percentage(&self) -> SingleValueMapper<u64>;
+
     #[view(getClaimableAmount)]
     #[storage_mapper("claimableAmount")]
     fn claimable_amount(
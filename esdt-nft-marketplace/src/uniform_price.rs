@@ -0,0 +1,184 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::auction::Auction;
+
+// bounds the gas needed to settle an auction
+pub const MAX_UNIFORM_PRICE_BIDS: usize = 100;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone)]
+pub struct UniformPriceBid<M: ManagedTypeApi> {
+    pub bidder: ManagedAddress<M>,
+    pub quantity: BigUint<M>,
+    pub unit_price: BigUint<M>,
+}
+
+pub struct UniformPriceSettlement<M: ManagedTypeApi> {
+    pub clearing_price: BigUint<M>,
+    pub nr_sold_tokens: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait UniformPriceModule:
+    crate::token_distribution::TokenDistributionModule
+    + crate::common_util_functions::CommonUtilFunctions
+    + multiversx_sc_modules::pause::PauseModule
+{
+    // bids are kept sorted by unit price, highest first. Bids at the same price keep the
+    // order they were placed in, so earlier bids are filled first. Once the auction is full,
+    // the lowest bid is refunded to make room for a higher one, but only if it would lose anyway.
+    fn insert_uniform_price_bid(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        new_bid: UniformPriceBid<Self::Api>,
+    ) {
+        let bids_mapper = self.uniform_price_bids(auction_id);
+        let mut bids = bids_mapper.get();
+        if bids.len() >= MAX_UNIFORM_PRICE_BIDS {
+            let lowest_bid_index = bids.len() - 1;
+            let lowest_bid = bids.get(lowest_bid_index);
+            require!(
+                new_bid.unit_price > lowest_bid.unit_price,
+                "Unit price must be higher than the lowest bid"
+            );
+
+            let mut higher_bids_quantity = new_bid.quantity.clone();
+            for bid in bids.iter().take(lowest_bid_index) {
+                higher_bids_quantity += &bid.quantity;
+            }
+            require!(
+                higher_bids_quantity >= auction.auctioned_tokens.amount,
+                "Auction is full, the lowest bid can still win"
+            );
+
+            self.transfer_or_save_payment(
+                &lowest_bid.bidder,
+                &auction.payment_token,
+                auction.payment_nonce,
+                &(&lowest_bid.quantity * &lowest_bid.unit_price),
+            );
+            bids.remove(lowest_bid_index);
+        }
+
+        let mut sorted_bids = ManagedVec::new();
+        let mut inserted = false;
+        for bid in bids.iter() {
+            if !inserted && new_bid.unit_price > bid.unit_price {
+                sorted_bids.push(new_bid.clone());
+                inserted = true;
+            }
+            sorted_bids.push(bid);
+        }
+        if !inserted {
+            sorted_bids.push(new_bid);
+        }
+
+        bids_mapper.set(&sorted_bids);
+    }
+
+    // the top bids fill the lot, and every winner pays the unit price of the lowest
+    // winning bid. The marginal bid may only be filled partially.
+    fn settle_uniform_price_bids(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        opt_keeper: Option<&ManagedAddress>,
+    ) -> UniformPriceSettlement<Self::Api> {
+        let bids = self.uniform_price_bids(auction_id).take();
+        let lot_size = &auction.auctioned_tokens.amount;
+
+        let mut nr_sold_tokens = BigUint::zero();
+        let mut clearing_price = BigUint::zero();
+        for bid in bids.iter() {
+            if &nr_sold_tokens >= lot_size {
+                break;
+            }
+
+            nr_sold_tokens += self.get_uniform_price_fill(&bid, lot_size, &nr_sold_tokens);
+            clearing_price = bid.unit_price;
+        }
+
+        let nft_type =
+            EgldOrEsdtTokenIdentifier::esdt(auction.auctioned_tokens.token_identifier.clone());
+        let nft_nonce = auction.auctioned_tokens.token_nonce;
+        let mut nr_filled_tokens = BigUint::zero();
+        for bid in bids.iter() {
+            let filled_quantity = self.get_uniform_price_fill(&bid, lot_size, &nr_filled_tokens);
+            nr_filled_tokens += &filled_quantity;
+
+            // refund losing bids and the overpayment of winning ones
+            let deposit = &bid.quantity * &bid.unit_price;
+            let refund = deposit - &filled_quantity * &clearing_price;
+            self.transfer_or_save_payment(
+                &bid.bidder,
+                &auction.payment_token,
+                auction.payment_nonce,
+                &refund,
+            );
+            self.transfer_or_save_payment(&bid.bidder, &nft_type, nft_nonce, &filled_quantity);
+        }
+
+        let nft_info = self.get_nft_info(&auction.auctioned_tokens.token_identifier, nft_nonce);
+        let mut sale_split_amounts = self.calculate_sale_split_values(
+            &(&clearing_price * &nr_sold_tokens),
+            &auction.creator_royalties_percentage,
+            &auction.marketplace_cut_percentage,
+        );
+        self.pay_keeper_reward(
+            opt_keeper,
+            &auction.payment_token,
+            auction.payment_nonce,
+            &mut sale_split_amounts.marketplace,
+        );
+        self.distribute_sale_payments(
+            &auction.payment_token,
+            auction.payment_nonce,
+            &nft_info.creator,
+            &auction.original_owner,
+            &sale_split_amounts,
+        );
+
+        // return the tokens that were not sold
+        self.transfer_or_save_payment(
+            &auction.original_owner,
+            &nft_type,
+            nft_nonce,
+            &(lot_size - &nr_sold_tokens),
+        );
+
+        UniformPriceSettlement {
+            clearing_price,
+            nr_sold_tokens,
+        }
+    }
+
+    fn get_uniform_price_fill(
+        &self,
+        bid: &UniformPriceBid<Self::Api>,
+        lot_size: &BigUint,
+        nr_filled_tokens: &BigUint,
+    ) -> BigUint {
+        if nr_filled_tokens >= lot_size {
+            return BigUint::zero();
+        }
+
+        let remaining_tokens = lot_size - nr_filled_tokens;
+        if bid.quantity < remaining_tokens {
+            bid.quantity.clone()
+        } else {
+            remaining_tokens
+        }
+    }
+
+    #[view(getUniformPriceMinQuantity)]
+    #[storage_mapper("uniformPriceMinQuantity")]
+    fn uniform_price_min_quantity(&self, auction_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[view(getUniformPriceBids)]
+    #[storage_mapper("uniformPriceBids")]
+    fn uniform_price_bids(
+        &self,
+        auction_id: u64,
+    ) -> SingleValueMapper<ManagedVec<UniformPriceBid<Self::Api>>>;
+}
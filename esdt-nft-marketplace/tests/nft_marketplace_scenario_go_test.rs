@@ -152,3 +152,158 @@ fn offer_token_with_active_auction_with_bid_go() {
 fn offer_token_withdraw_reoffer_go() {
     multiversx_sc_scenario::run_go("mandos/offer_token_withdraw_reoffer.scen.json");
 }
+
+#[test]
+fn auction_dutch_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_dutch.scen.json");
+}
+
+#[test]
+fn auction_soft_close_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_soft_close.scen.json");
+}
+
+#[test]
+fn auction_reserve_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_reserve.scen.json");
+}
+
+#[test]
+fn auction_sealed_bid_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_sealed_bid.scen.json");
+}
+
+#[test]
+fn auction_second_price_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_second_price.scen.json");
+}
+
+#[test]
+fn auction_bundle_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_bundle.scen.json");
+}
+
+#[test]
+fn auction_update_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_update.scen.json");
+}
+
+#[test]
+fn listing_fixed_price_go() {
+    multiversx_sc_scenario::run_go("mandos/listing_fixed_price.scen.json");
+}
+
+#[test]
+fn listing_multiple_payment_tokens_go() {
+    multiversx_sc_scenario::run_go("mandos/listing_multiple_payment_tokens.scen.json");
+}
+
+#[test]
+fn auction_proxy_bid_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_proxy_bid.scen.json");
+}
+
+#[test]
+fn auction_with_min_bid_diff_percentage_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_with_min_bid_diff_percentage.scen.json");
+}
+
+#[test]
+fn offer_collection_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_collection.scen.json");
+}
+
+#[test]
+fn offer_traits_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_traits.scen.json");
+}
+
+#[test]
+fn offer_counter_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_counter.scen.json");
+}
+
+#[test]
+fn offer_cleanup_expired_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_cleanup_expired.scen.json");
+}
+
+#[test]
+fn offer_partial_fill_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_partial_fill.scen.json");
+}
+
+#[test]
+fn offer_update_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_update.scen.json");
+}
+
+#[test]
+fn offer_barter_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_barter.scen.json");
+}
+
+#[test]
+fn offer_on_listing_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_on_listing.scen.json");
+}
+
+#[test]
+fn offer_reject_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_reject.scen.json");
+}
+
+#[test]
+fn auction_end_batch_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_end_batch.scen.json");
+}
+
+#[test]
+fn auction_uniform_price_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_uniform_price.scen.json");
+}
+
+#[test]
+fn auction_buy_now_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_buy_now.scen.json");
+}
+
+#[test]
+fn listing_private_go() {
+    multiversx_sc_scenario::run_go("mandos/listing_private.scen.json");
+}
+
+#[test]
+fn offer_nonced_payment_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_nonced_payment.scen.json");
+}
+
+#[test]
+fn auction_uniform_price_full_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_uniform_price_full.scen.json");
+}
+
+#[test]
+fn listing_update_prices_go() {
+    multiversx_sc_scenario::run_go("mandos/listing_update_prices.scen.json");
+}
+
+#[test]
+fn offer_barter_on_listing_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_barter_on_listing.scen.json");
+}
+
+#[test]
+fn offer_min_offer_listing_nonce_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_min_offer_listing_nonce.scen.json");
+}
+
+#[test]
+fn offer_min_offer_listing_amount_go() {
+    multiversx_sc_scenario::run_go("mandos/offer_min_offer_listing_amount.scen.json");
+}
+
+#[test]
+fn auction_uniform_price_full_lot_go() {
+    multiversx_sc_scenario::run_go("mandos/auction_uniform_price_full_lot.scen.json");
+}
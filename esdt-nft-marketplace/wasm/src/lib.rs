@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           23
+// Endpoints:                           91
 // Async Callback (empty):               1
-// Total number of exported functions:  25
+// Total number of exported functions:  93
 
 #![no_std]
 #![feature(alloc_error_handler, lang_items)]
@@ -20,24 +20,92 @@ multiversx_sc_wasm_adapter::endpoints! {
     (
         setCutPercentage
         auctionToken
+        listToken
+        auctionTokenDutch
+        auctionTokenReserve
+        auctionTokenSealed
+        auctionTokenUniformPrice
+        setAuctionSoftClose
+        setAuctionMinBidDiffPercentage
+        setListingMinOffer
+        updateListingPrice
+        setAuctionBuyNowPrice
+        setAuctionAllowlist
+        setAuctionRequiredToken
+        updateAuction
         endAuction
+        endAuctions
         withdraw
+        getCurrentDutchPrice
+        isListing
+        getListingPrice
+        getListingPrices
         getFullAuctionData
+        getListingMinOffer
+        getAuctionAllowlist
+        getAuctionRequiredToken
+        getAuctionCountdownDuration
+        getAuctionSecondPrice
+        getAuctionSecondHighestBid
+        getAuctionMinBidDiffPercentage
+        getAuctionBuyNowPrice
         getLastValidAuctionId
         getMarketplaceCutPercentage
         sendOffer
+        sendCollectionOffer
         withdrawOffer
+        updateOffer
+        cleanupExpiredOffers
+        setBarterOfferFee
+        setExpiredOfferBountyPercentage
         acceptOffer
         withdrawAuctionAndAcceptOffer
+        rejectOffer
+        acceptOfferOnListing
+        counterOffer
+        acceptCounterOffer
+        withdrawCounterOffer
         getFullOfferData
+        getCounterOffer
         getLastValidOfferId
+        getOfferRequiredTraits
+        getOfferPricePerUnit
+        getOfferBarterTokens
+        getOfferListingId
+        getExpiredOfferBountyPercentage
+        getBarterOfferFee
         bid
+        proxyBid
         buySft
+        buyDutchAuction
+        buyNft
+        buyNow
+        sealedBid
+        revealBid
+        uniformPriceBid
+        setKeeperRewardPercentage
         claimTokens
+        getAuctionBundledTokens
+        getAuctionBundleWeights
+        getAuctionBundleRoyalties
+        getKeeperRewardPercentage
         getClaimableAmount
         addTokensToWhitelist
         removeTokensFromWhitelist
         getWhitelistedTokens
+        setDefaultSoftClose
+        setMaxSoftClose
+        getDefaultSoftCloseWindow
+        getDefaultSoftCloseExtension
+        getMaxSoftCloseWindow
+        getMaxSoftCloseExtension
+        getAuctionSoftCloseWindow
+        getAuctionSoftCloseExtension
+        setSlashUnrevealedBids
+        getSlashUnrevealedBids
+        getAuctionRevealDeadline
+        getUniformPriceMinQuantity
+        getUniformPriceBids
         pause
         unpause
         isPaused